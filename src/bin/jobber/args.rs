@@ -1,14 +1,19 @@
 //! Command line arguments & help
 //!
-use clap::Parser;
+use crate::complete::{complete_columns, complete_profile_list, complete_profiles, complete_tags};
+use clap::{ArgGroup, Parser};
+use clap_complete::engine::ArgValueCompleter;
+use std::ffi::OsString;
 
 /// Command line tool for tracking work time
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Clone)]
 #[command(
     author,
     version,
     about,
     long_about,
+    group(ArgGroup::new("unattended").args(["non_interactive", "porcelain"]).multiple(true)),
+    group(ArgGroup::new("switch_checks").args(["enable_checks", "disable_checks"]).multiple(true)),
     after_help(
         "\
 Arguments:
@@ -16,7 +21,7 @@ Arguments:
   <FILENAME>, <LEGACY_IMPORT>
         File path and name.
 
-  <START>, <BACK>, <END>
+  <START>, <BACK>, <END>, <SWITCH>, <PAUSE>, <RESUME>
         Date and time in one of the following formats:
 
         m/d/y,H:M   d.m.y,H:M   y-m-d,H:M
@@ -27,6 +32,19 @@ Arguments:
         y = year    m = month   d = day of month
         H = hour    M = minute
 
+        or relative to now in one of the following formats:
+
+        -D          +D          now-D       now+D
+        W,H:M       W           R,H:M       R
+
+        D = duration (see <DURATION>)
+        W = weekday (e.g. mon or monday)
+        R = today, yesterday or tomorrow
+
+  <LIKE>
+        Position of a job or a term to search for within messages and tags.
+        Without value a list of recent jobs will be shown to pick one from.
+
   <DURATION>
         Duration in one of the following formats:
 
@@ -41,7 +59,7 @@ Arguments:
   <TAGS>
         List of comma separated tag names (omit spaces)
 
-  <LIST>, <REPORT>, <EXPORT>, <LIST_TAGS>
+  <LIST>, <REPORT>, <EXPORT>, <LIST_TAGS>, <GAPS>
         Time or positional range in one of the following formats:
 
         f-t         f-          p         ~C
@@ -57,7 +75,8 @@ Arguments:
 
   <CSV>
         List of comma separated column names (omit spaces)
-        Available columns: start, end, duration, hours, message, tags, pay, rate, resolution
+        Available columns: start, end, duration, hours, raw-hours, message, tags, pay, billable, invoice,
+        rate, resolution
   <RESOLUTION>
         Work time resolution in fractional hours
 
@@ -67,8 +86,89 @@ Arguments:
   <MAX_HOURS>
         Maximum amount of work hours as integer number
 
+  <MIN_HOURS>
+        Minimum billable hours per job as floating point number
+
+  <ROUNDING>
+        up, nearest or down
+
+  <ROUNDING_LEVEL>
+        job, day or invoice
+
+  <DATE_ORDER>
+        Order of day, month and year which is used to display dates and
+        to read dates which are separated by slashes:
+
+        mdy         dmy         ymd
+
+  <CLOCK>
+        12 or 24 (hours)
+
+  <EXPORT_FORMAT>
+        strftime like format (e.g. %Y-%m-%d %H:%M)
+
+  <TIME_ZONE>
+        local or IANA time zone name (e.g. Europe/Berlin)
+
+  <WORK_HOURS>
+        Begin and end of work (e.g. 8:00-17:00)
+
+  <MIN_GAP>
+        Duration (like in <DURATION>)
+
+  <DAY_BOUNDARY>
+        Time (H:M) at which jobs are split into work days (default: 0:00)
+
+  <SPLIT>
+        Position of a job to split.
+
+  <AT>
+        Date and time (like in <START>) to split the job at.
+
+  <MERGE>
+        Range (like in <LIST>) of jobs to merge.
+
   <EDIT>
-        Position of a job to edit.
+        Position of a job to edit or a range (like in <LIST>) of jobs to edit at once.
+        When editing multiple jobs only tags and message can be changed or
+        start and end can be shifted.
+
+  <WITH_TAGS>
+        List of comma separated tag names (omit spaces)
+
+  <SHIFT>
+        Duration (like in <DURATION>) with an optional leading - or +
+
+  <STATUS>
+        Format of the status with the placeholders {pos}, {start}, {tags},
+        {message}, {duration} and {hours} (default: '{tags} {message} {duration}')
+        or json.
+
+  <POLICY>
+        Comma separated list of policies which are fail (default), warn or accept.
+        Each may be prefixed with a check to apply only to it (e.g. overlaps=accept)
+        or applies to all other checks: overlaps, unknown-tags, confirm-deletion,
+        confirm-modification, max-hours
+
+  <ENABLE_CHECKS>, <DISABLE_CHECKS>
+        Comma separated list of checks: overlaps, unknown-tags, confirm-deletion,
+        confirm-modification, locked, max-hours
+
+  <PROFILE>, <USE_PROFILE>
+        Name of a profile within the configuration file or default to use the
+        database given by database.
+
+  <PROFILES>
+        Comma separated list of profile names (like in <PROFILE>) or all.
+
+  <COMPLETIONS>
+        bash, elvish, fish, powershell or zsh
+
+  <INVOICE>, <UNINVOICE>
+        Range (like in <LIST>) of jobs to mark as invoiced or to unmark.
+
+  <REFERENCE>
+        Invoice number or any other text to identify the invoice.
 "
     ),
     help_template(
@@ -92,6 +192,18 @@ pub struct Args {
     #[arg(short, long)]
     pub filename: Option<String>,
 
+    /// Use the database of the given profile instead of the selected one
+    #[arg(long, conflicts_with("filename"), add = ArgValueCompleter::new(complete_profiles))]
+    pub profile: Option<String>,
+
+    /// Select the profile whose database shall be used from now on
+    #[arg(long = "use-profile", conflicts_with_all(["filename","profile"]), add = ArgValueCompleter::new(complete_profiles))]
+    pub use_profile: Option<String>,
+
+    /// Report jobs of several profiles and sum up their hours (use with -r)
+    #[arg(long, requires("report"), conflicts_with_all(["filename","profile"]), add = ArgValueCompleter::new(complete_profile_list))]
+    pub profiles: Option<String>,
+
     /// Add job now or at the given starting time
     #[arg(short, long)]
     pub start: Option<Option<String>>,
@@ -100,6 +212,10 @@ pub struct Args {
     #[arg(short, long, conflicts_with("start"))]
     pub back: Option<Option<String>>,
 
+    /// Select the job which --back copies from by position or search term (or pick one)
+    #[arg(long, requires("back"))]
+    pub like: Option<Option<String>>,
+
     /// End job now or at the given time
     #[arg(short, long)]
     pub end: Option<Option<String>>,
@@ -113,9 +229,21 @@ pub struct Args {
     pub message: Option<Option<String>>,
 
     /// Add list of tags separated by comma or for reporting filter by tags
-    #[arg(short, long)]
+    #[arg(short, long, add = ArgValueCompleter::new(complete_tags))]
     pub tags: Option<Option<String>>,
 
+    /// End the open job and start a new one now or at the given time (copies description like --back)
+    #[arg(long, conflicts_with_all(["start","end","back","duration","pause","resume"]))]
+    pub switch: Option<Option<String>>,
+
+    /// Pause the open job now or at the given time
+    #[arg(long, conflicts_with_all(["start","end","back","duration","resume"]))]
+    pub pause: Option<Option<String>>,
+
+    /// Resume the paused open job now or at the given time
+    #[arg(long, conflicts_with_all(["start","end","back","duration","pause"]))]
+    pub resume: Option<Option<String>>,
+
     /// List all jobs or selective by position(s) or time(s)
     #[arg(short, long, conflicts_with_all(["start","end","back","message","report","edit"]))]
     pub list: Option<Option<String>>,
@@ -124,6 +252,10 @@ pub struct Args {
     #[arg(short, long, conflicts_with_all(["start","end","back","message","list","edit"]))]
     pub report: Option<Option<String>>,
 
+    /// List gaps between jobs within working hours of all days or selective by position(s) or time(s)
+    #[arg(short='G', long, conflicts_with_all(["start","end","back","message","list","report","edit"]))]
+    pub gaps: Option<Option<String>>,
+
     /// Export all jobs or selective by position(s) or time(s) as CSV
     #[arg(short='E', long="export", conflicts_with_all(["start","end","back","message","list","report","edit"]))]
     pub export: Option<Option<String>>,
@@ -132,7 +264,8 @@ pub struct Args {
     #[arg(
         long = "csv",
         requires("export"),
-        default_value = "tags,start,hours,message"
+        default_value = "tags,start,hours,message",
+        add = ArgValueCompleter::new(complete_columns)
     )]
     pub csv: String,
 
@@ -152,6 +285,66 @@ pub struct Args {
     #[arg(long="max-hours", conflicts_with_all(["start","end","back","message","list","report","edit"]))]
     pub max_hours: Option<u32>,
 
+    /// Set minimum billable hours per job (can be combined with --tags)
+    #[arg(long="min-hours", conflicts_with_all(["start","end","back","message","list","report","edit"]))]
+    pub min_hours: Option<f64>,
+
+    /// Mark job(s) as billable or set billable as default (can be combined with --tags)
+    #[arg(long, conflicts_with("non_billable"))]
+    pub billable: bool,
+
+    /// Mark job(s) as non-billable or set non-billable as default (can be combined with --tags)
+    #[arg(long = "non-billable")]
+    pub non_billable: bool,
+
+    /// Set how hours are rounded to the resolution (up, nearest or down) (can be combined with --tags)
+    #[arg(long, conflicts_with_all(["start","end","back","message","list","report","edit"]))]
+    pub rounding: Option<String>,
+
+    /// Set if hours are rounded per job, day or invoice (can be combined with --tags)
+    #[arg(long="rounding-level", conflicts_with_all(["start","end","back","message","list","report","edit"]))]
+    pub rounding_level: Option<String>,
+
+    /// Set the preferred order of day, month and year (mdy, dmy or ymd)
+    #[arg(long="date-order", conflicts_with_all(["start","end","back","message","list","report","edit"]))]
+    pub date_order: Option<String>,
+
+    /// Set the preferred clock (12 or 24 hours)
+    #[arg(long, conflicts_with_all(["start","end","back","message","list","report","edit"]))]
+    pub clock: Option<String>,
+
+    /// Set the format of date and time within CSV export (strftime format)
+    #[arg(long="export-format", conflicts_with_all(["start","end","back","message","list","report","edit"]))]
+    pub export_format: Option<String>,
+
+    /// Set the time zone for reading, writing and reporting times (IANA name or local)
+    #[arg(long="time-zone", conflicts_with_all(["start","end","back","message","list","report","edit"]))]
+    pub time_zone: Option<String>,
+
+    /// Set the daily working hours in which gaps are searched (e.g. 8:00-17:00)
+    #[arg(long="work-hours", conflicts_with_all(["start","end","back","message","list","report","edit"]))]
+    pub work_hours: Option<String>,
+
+    /// Set the minimum duration of gaps between jobs
+    #[arg(long="min-gap", conflicts_with_all(["start","end","back","message","list","report","edit"]))]
+    pub min_gap: Option<String>,
+
+    /// Set the time at which a work day ends and the next one begins (e.g. 4:00)
+    #[arg(long="day-boundary", conflicts_with_all(["start","end","back","message","list","report","edit"]))]
+    pub day_boundary: Option<String>,
+
+    /// Enable checks for this call
+    #[arg(long = "enable-checks")]
+    pub enable_checks: Option<String>,
+
+    /// Disable checks for this call
+    #[arg(long = "disable-checks")]
+    pub disable_checks: Option<String>,
+
+    /// Store enabled and disabled checks in database
+    #[arg(long = "save-checks", requires("switch_checks"), conflicts_with_all(["start","end","back","message","list","report","edit"]))]
+    pub save_checks: bool,
+
     /// Import jobs from legacy jobber (ruby version)
     #[arg(long="legacy-import", conflicts_with_all(["start","end","back","tags","message","list","report","edit"]))]
     pub legacy_import: Option<String>,
@@ -160,15 +353,119 @@ pub struct Args {
     #[arg(short='T', long="list-tags", conflicts_with_all(["start","end","back","message","list","report","edit"]))]
     pub list_tags: Option<Option<String>>,
 
-    /// Edit some items of a job by it's position
+    /// Edit some items of a job by it's position or of all jobs within a range
     #[arg(long="edit", conflicts_with_all(["back","list","report","delete"]))]
-    pub edit: Option<Option<usize>>,
+    pub edit: Option<Option<String>>,
+
+    /// Only edit jobs within the range which have the given tags
+    #[arg(long = "with-tags", requires("edit"), add = ArgValueCompleter::new(complete_tags))]
+    pub with_tags: Option<String>,
+
+    /// Shift start and end of the edited jobs by a duration (prepend - to shift backwards)
+    #[arg(long, requires("edit"), allow_hyphen_values(true), conflicts_with_all(["start","end","duration"]))]
+    pub shift: Option<String>,
+
+    /// Split a job by it's position into two (use --at to give the time)
+    #[arg(long, requires("at"), conflicts_with_all(["start","back","end","list","report","edit","delete"]))]
+    pub split: Option<usize>,
+
+    /// Time at which to split a job
+    #[arg(long, requires("split"))]
+    pub at: Option<String>,
+
+    /// Merge following jobs within a range into one
+    #[arg(long, conflicts_with_all(["start","back","end","list","report","edit","delete","split"]))]
+    pub merge: Option<String>,
 
     /// Delete some jobs by it's position
     #[arg(long="delete", conflicts_with_all(["start","back","end","list","report","edit"]))]
     pub delete: Option<String>,
 
+    /// Restore some deleted jobs by it's position
+    #[arg(long="restore", conflicts_with_all(["start","back","end","list","report","edit","delete"]))]
+    pub restore: Option<String>,
+
+    /// Mark some jobs by it's position as invoiced (use --reference to give the invoice)
+    #[arg(long, requires("reference"), conflicts_with_all(["start","back","end","list","report","edit","delete","restore"]))]
+    pub invoice: Option<String>,
+
+    /// Reference (e.g. number) of the invoice
+    #[arg(long, requires("invoice"))]
+    pub reference: Option<String>,
+
+    /// Remove invoice from some jobs by it's position (requires --unlock)
+    #[arg(long, requires("unlock"), conflicts_with_all(["start","back","end","list","report","edit","delete","restore","invoice"]))]
+    pub uninvoice: Option<String>,
+
+    /// Allow changes of invoiced jobs
+    #[arg(long)]
+    pub unlock: bool,
+
+    /// Scan the database for problems and offer to fix them
+    #[arg(long, conflicts_with_all(["start","back","end","list","report","edit","delete","restore"]))]
+    pub doctor: bool,
+
+    /// Check if the open job exceeds the maximum work hours or crosses midnight (exits with an error if so)
+    #[arg(long, conflicts_with_all(["start","back","end","list","report","edit","delete","restore","doctor"]))]
+    pub remind: bool,
+
+    /// Print status of the open job without any other output (e.g. for a shell prompt)
+    #[arg(long, conflicts_with_all(["start","back","end","list","report","edit","delete","restore","doctor","remind"]))]
+    pub status: Option<Option<String>>,
+
+    /// Browse and edit jobs in a full screen terminal user interface
+    #[arg(long="tui", conflicts_with_all(["start","back","end","list","report","edit","delete","restore"]))]
+    pub tui: bool,
+
+    /// Print script which enables completion for the given shell
+    #[arg(long)]
+    pub completions: Option<String>,
+
+    /// Do not print any information besides errors, questions and requested output
+    #[arg(short, long)]
+    pub quiet: bool,
+
+    /// Print the result as JSON instead of any other output (for scripting, implies --non-interactive)
+    #[arg(long, conflicts_with("tui"))]
+    pub porcelain: bool,
+
+    /// Never ask the user but handle warnings by policy and fail if input is missing
+    #[arg(long = "non-interactive", conflicts_with("tui"))]
+    pub non_interactive: bool,
+
+    /// Policies to handle warnings in non-interactive mode (e.g. overlaps=fail,unknown-tags=accept)
+    #[arg(long, requires("unattended"))]
+    pub policy: Option<String>,
+
     /// Run dry (don't write any changes into database)
     #[arg(short = 'D', long = "dry")]
     pub dry: bool,
 }
+
+/// Options which take a time and so may be followed by a negative offset like `-20m`.
+const TIME_OPTIONS: [&str; 7] = ["-s", "--start", "-e", "--end", "-b", "--back", "--at"];
+
+/// Join options which take a time with a following negative offset (like `-s -20m` into
+/// `-s=-20m`) so that the offset is not taken for an option when parsing.
+pub fn join_offsets<I, T>(args: I) -> Vec<OsString>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString>,
+{
+    let mut result: Vec<OsString> = Vec::new();
+    for arg in args.into_iter().map(Into::into) {
+        let is_offset = arg
+            .to_str()
+            .and_then(|arg| arg.strip_prefix('-'))
+            .is_some_and(|offset| offset.starts_with(|c: char| c.is_ascii_digit()));
+        if let Some(option) = result.last_mut() {
+            if is_offset && option.to_str().is_some_and(|o| TIME_OPTIONS.contains(&o)) {
+                option.push("=");
+                option.push(arg);
+                continue;
+            }
+        }
+        result.push(arg);
+    }
+    result
+}
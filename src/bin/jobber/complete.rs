@@ -0,0 +1,116 @@
+//! Shell completion including dynamic completion of tags and export columns.
+
+use crate::{Config, DEFAULT_PROFILE};
+use clap_complete::{engine::CompletionCandidate, env::Shells};
+use jobberdb::prelude::*;
+use std::ffi::{OsStr, OsString};
+
+/// Environment variable which activates completion (see [clap_complete::CompleteEnv]).
+pub const COMPLETE_VAR: &str = "COMPLETE";
+
+/// Write the script which registers completion for the given shell.
+pub fn completions<W: std::io::Write>(w: &mut W, shell: &str) -> Result<(), Error> {
+    let shells = Shells::builtins();
+    let completer = shells
+        .completer(shell)
+        .ok_or(Error::UnknownShell(shell.to_string()))?;
+    completer.write_registration(COMPLETE_VAR, "jobber", "jobber", "jobber", w)?;
+    Ok(())
+}
+
+/// Complete the last of some comma separated tags with the tags within the database.
+pub fn complete_tags(current: &OsStr) -> Vec<CompletionCandidate> {
+    complete_tags_of(std::env::args_os(), current)
+}
+
+/// Complete tags with the tags within the database which is selected by the given
+/// command line (by `-f`/`--filename` or `--profile`).
+pub fn complete_tags_of(
+    args: impl Iterator<Item = OsString>,
+    current: &OsStr,
+) -> Vec<CompletionCandidate> {
+    let tags = if let Some(jobs) = load(args) {
+        jobs.tags()
+    } else {
+        return Vec::new();
+    };
+    complete_list(current, tags.0.iter().map(|tag| tag.as_str()))
+}
+
+/// Complete the last of some comma separated export column names.
+pub fn complete_columns(current: &OsStr) -> Vec<CompletionCandidate> {
+    complete_list(current, COLUMNS.iter().copied())
+}
+
+/// Complete a profile name with the profiles within the configuration file.
+pub fn complete_profiles(current: &OsStr) -> Vec<CompletionCandidate> {
+    let current = current.to_string_lossy();
+    profiles()
+        .into_iter()
+        .filter(|profile| profile.starts_with(current.as_ref()))
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
+/// Complete the last of some comma separated profile names.
+pub fn complete_profile_list(current: &OsStr) -> Vec<CompletionCandidate> {
+    let profiles = profiles();
+    complete_list(current, profiles.iter().map(|profile| profile.as_str()))
+}
+
+/// Complete the last item of a comma separated list with the matching candidates.
+pub fn complete_list<'a>(
+    current: &OsStr,
+    candidates: impl Iterator<Item = &'a str>,
+) -> Vec<CompletionCandidate> {
+    let current = current.to_string_lossy();
+    let (head, last) = match current.rfind(',') {
+        Some(n) => current.split_at(n + 1),
+        None => ("", current.as_ref()),
+    };
+    // do not offer items which are already in the list
+    let used: Vec<&str> = head.split(',').collect();
+    candidates
+        .filter(|candidate| candidate.starts_with(last) && !used.contains(candidate))
+        .map(|candidate| CompletionCandidate::new(format!("{head}{candidate}")))
+        .collect()
+}
+
+/// Get names of all profiles which are configured.
+fn profiles() -> Vec<String> {
+    let mut profiles = vec![DEFAULT_PROFILE.to_string()];
+    if let Ok(cfg) = confy::load::<Config>("jobber", "config") {
+        profiles.extend(cfg.profiles.into_keys());
+    }
+    profiles
+}
+
+/// Load database which is selected by the given command line or configured (without any output).
+fn load(args: impl Iterator<Item = OsString>) -> Option<Jobs> {
+    let (filename, profile) = selection(args);
+    if let Some(filename) = filename {
+        return Jobs::load(&filename).ok();
+    }
+    let cfg: Config = confy::load("jobber", "config").ok()?;
+    Jobs::load(&cfg.database(profile.as_deref()).ok()?).ok()
+}
+
+/// Get values of options `-f`/`--filename` and `--profile` from a command line.
+fn selection(args: impl Iterator<Item = OsString>) -> (Option<String>, Option<String>) {
+    let (mut filename, mut profile) = (None, None);
+    let mut args = args.map(|arg| arg.to_string_lossy().to_string());
+    while let Some(arg) = args.next() {
+        if let Some(value) = arg.strip_prefix("--filename=") {
+            filename = Some(value.to_string());
+        } else if let Some(value) = arg.strip_prefix("--profile=") {
+            profile = Some(value.to_string());
+        } else if arg == "-f" || arg == "--filename" {
+            filename = args.next();
+        } else if arg == "--profile" {
+            profile = args.next();
+        } else if let Some(value) = arg.strip_prefix("-f").filter(|value| !value.is_empty()) {
+            filename = Some(value.to_string());
+        }
+    }
+    (filename, profile)
+}
@@ -5,22 +5,85 @@
 //! See module [jobberdb] for the beef..
 
 mod args;
+mod complete;
+mod profile;
 #[cfg(test)]
 mod tests;
+mod tui;
 
 use args::Args;
-use clap::Parser;
+use clap::{CommandFactory, Parser};
+use clap_complete::CompleteEnv;
 use jobberdb::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use termion::{color::*, style};
 
 const ASK_FOR_MESSAGE: &str = "You need to enter a message about what you did to finish the job.\n\
                                 Finish input with empty line (or Ctrl+C to cancel):";
 
+/// Name of the profile which uses the database given by `database` within [Config].
+const DEFAULT_PROFILE: &str = "default";
+
 /// System side configuration.
 #[derive(Debug, Serialize, Deserialize)]
 struct Config {
+    /// Database file which is used if no profile is selected.
     database: String,
+    /// Name of the selected profile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    profile: Option<String>,
+    /// Database files of named profiles.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    profiles: BTreeMap<String, String>,
+}
+
+impl Config {
+    /// Get database file of the given profile or of the selected one if none is given.
+    fn database(&self, profile: Option<&str>) -> Result<String, Error> {
+        let name = if let Some(name) = profile.or(self.profile.as_deref()) {
+            name
+        } else {
+            return Ok(self.database.clone());
+        };
+        if let Some(database) = self.profiles.get(name) {
+            Ok(database.clone())
+        } else if name == DEFAULT_PROFILE {
+            Ok(self.database.clone())
+        } else {
+            Err(Error::UnknownProfile(name.to_string()))
+        }
+    }
+    /// Get names and database files of comma separated profiles (or of all including the
+    /// default profile if `all` is given).
+    fn profiles(&self, names: &str) -> Result<Vec<(String, String)>, Error> {
+        let names: Vec<&str> = if names == "all" {
+            std::iter::once(DEFAULT_PROFILE)
+                .chain(
+                    self.profiles
+                        .keys()
+                        .map(|name| name.as_str())
+                        .filter(|name| *name != DEFAULT_PROFILE),
+                )
+                .collect()
+        } else {
+            names.split(',').collect()
+        };
+        names
+            .into_iter()
+            .map(|name| Ok((name.to_string(), self.database(Some(name))?)))
+            .collect()
+    }
+    /// Select the profile to use from now on and return its database file.
+    fn use_profile(&mut self, name: &str) -> Result<String, Error> {
+        let database = self.database(Some(name))?;
+        self.profile = if name == DEFAULT_PROFILE {
+            None
+        } else {
+            Some(name.to_string())
+        };
+        Ok(database)
+    }
 }
 
 impl Default for Config {
@@ -31,13 +94,21 @@ impl Default for Config {
             ".".to_string()
         };
         let path = format!("{}/jobber.json", home);
-        Self { database: path }
+        Self {
+            database: path,
+            profile: None,
+            profiles: BTreeMap::new(),
+        }
     }
 }
 
 /// Main which just catches errors.
 fn main() {
-    let args = Args::parse();
+    // complete command line if called by shell
+    CompleteEnv::with_factory(Args::command)
+        .var(complete::COMPLETE_VAR)
+        .complete();
+    let args = Args::parse_from(args::join_offsets(std::env::args_os()));
     let context = Context::now();
     if let Err(err) = run(&mut std::io::stdout(), args, Checks::all(), &context) {
         eprintln!(
@@ -47,6 +118,7 @@ fn main() {
             Fg(Reset),
             style::Reset
         );
+        std::process::exit(err.exit_code());
     }
 }
 
@@ -57,27 +129,77 @@ fn run<W: std::io::Write>(
     checks: Checks,
     context: &Context,
 ) -> Result<(), Error> {
+    // print completion script
+    if let Some(shell) = &args.completions {
+        return complete::completions(w, shell);
+    }
     let dry = args.dry;
+    let porcelain = args.porcelain;
+    // omit any chatter on stderr (status and porcelain output are shown without any)
+    let quiet = args.quiet || porcelain || args.status.is_some();
 
-    // get filename from config or arguments
+    // select profile for future calls
+    if let Some(name) = &args.use_profile {
+        let mut cfg: Config = confy::load("jobber", "config").map_err(Error::Confy)?;
+        let database = cfg.use_profile(name)?;
+        confy::store("jobber", "config", &cfg).map_err(Error::Confy)?;
+        if !quiet {
+            eprintln!("Using profile '{name}' with database file '{database}'");
+        }
+        return Ok(());
+    }
+    // report jobs of several profiles
+    if let Some(names) = &args.profiles {
+        if !matches!(parse(args.clone(), None, context)?, Command::Report { .. }) {
+            return Err(Error::ProfilesWithoutReport);
+        }
+        let cfg: Config = confy::load("jobber", "config").map_err(Error::Confy)?;
+        // parse arguments for every profile with it's own settings
+        return profile::report(
+            w,
+            &cfg.profiles(names)?,
+            || parse(args.clone(), None, context),
+            context,
+        );
+    }
+
+    // get filename from arguments or from config (of the given or selected profile)
     let filename = if let Some(filename) = &args.filename {
         filename.clone()
     } else {
         let cfg: Config = confy::load("jobber", "config").map_err(Error::Confy)?;
-        cfg.database
+        cfg.database(args.profile.as_deref())?
     };
 
+    // print status of the open job without loading the whole database
+    if let (Some(format), false) = (&args.status, porcelain) {
+        let format = format.as_deref().unwrap_or(STATUS_FORMAT);
+        let open = match Jobs::load_open(&filename) {
+            Err(Error::Io(_)) => None,
+            result => result?,
+        };
+        let status = format_status(open.as_ref().map(|(pos, job)| (*pos, job)), format, context)?;
+        if !status.is_empty() {
+            writeln!(w, "{status}")?;
+        }
+        return Ok(());
+    }
+
     // load database from file or create new
     let mut jobs = match Jobs::load(&filename) {
         Ok(jobs) => {
-            eprintln!(
-                "Loaded database ({} entries) from file '{filename}'",
-                jobs.count()
-            );
+            if !quiet {
+                eprintln!(
+                    "Loaded database ({} entries) from file '{filename}'",
+                    jobs.count()
+                );
+            }
             jobs
         }
         Err(Error::Io(_)) => {
-            eprintln!("Beginning new database file '{filename}'");
+            if !quiet {
+                eprintln!("Beginning new database file '{filename}'");
+            }
             Jobs::new()
         }
         Err(err) => {
@@ -85,10 +207,150 @@ fn run<W: std::io::Write>(
         }
     };
 
+    // handle warnings by policies instead of asking the user (always in porcelain mode)
+    let policies = if args.non_interactive || porcelain {
+        Some(if let Some(policy) = &args.policy {
+            Policies::parse(policy)?
+        } else {
+            Policies::default()
+        })
+    } else {
+        None
+    };
+    // enable or disable checks like configured and then like given by arguments
+    let checks = if let Some(configured) = &jobs.configuration.settings.checks {
+        checks.switch(configured)
+    } else {
+        checks
+    };
+    let checks = if let Some(switched) = switch_checks(&args.enable_checks, &args.disable_checks)? {
+        checks.switch(&switched)
+    } else {
+        checks
+    };
+    // allow changing invoiced jobs
+    let checks = if args.unlock {
+        checks.without(Check::Locked)
+    } else {
+        checks
+    };
+    // browse and edit jobs in full screen mode
+    if args.tui {
+        tui::run(&mut jobs, checks, context)?;
+        return save(&mut jobs, &filename, dry, quiet);
+    }
     // parse arguments into a command
     let mut command = parse(args, jobs.open_start(), context)?;
-    // process command on database
-    if let Ok(operation) = match jobs.process(w, &command, checks, context) {
+    // let user pick a template job
+    if command.template() == Some(&Template::Pick) {
+        if policies.is_some() {
+            return Err(Error::PickTemplate);
+        }
+        command.set_template(pick(&jobs)?);
+    }
+    // process command on database (in porcelain mode output result as JSON which includes any output)
+    let operation = if porcelain {
+        let mut output = Vec::new();
+        let operation = process(
+            &mut output,
+            &mut jobs,
+            &mut command,
+            checks.clone(),
+            policies.as_ref(),
+            context,
+        )?;
+        let mut json = operation.to_json(&jobs, context);
+        if !output.is_empty() {
+            json["output"] = plain(&output).into();
+        }
+        writeln!(w, "{json}")?;
+        operation
+    } else {
+        process(
+            w,
+            &mut jobs,
+            &mut command,
+            checks.clone(),
+            policies.as_ref(),
+            context,
+        )?
+    };
+    let interactive = policies.is_none() && termion::is_tty(&std::io::stdin());
+    if !quiet {
+        eprintln!("{}", operation);
+    }
+    // offer to fill any gaps
+    if let Operation::Gaps(gaps, _, _) = &operation {
+        if interactive {
+            fill(w, &mut jobs, gaps, &checks, context)?;
+        }
+    }
+    // offer to fix any problems
+    if let Operation::Doctor(problems) = &operation {
+        if interactive {
+            repair(w, &mut jobs, problems, &checks, context)?;
+        }
+    }
+    // offer to end a forgotten open job or fail
+    if let Operation::Remind(Some(reminder)) = &operation {
+        if interactive
+            && ask(
+                &format!(
+                    "End job at position {} at {}?",
+                    reminder.pos() + 1,
+                    format::end(&reminder.end())
+                ),
+                false,
+            )?
+        {
+            let mut command = reminder.command();
+            eprintln!(
+                "{}",
+                process(w, &mut jobs, &mut command, checks.clone(), None, context)?
+            );
+        } else {
+            return Err(Error::ForgottenJob(reminder.pos()));
+        }
+    }
+    if !quiet && !operation.reports_open_job() {
+        if let Some(job) = jobs.get_open_with_pos() {
+            eprintln!(
+                "{}{}There is an open Job at position {pos}!{}{}",
+                Fg(Yellow),
+                style::Bold,
+                Fg(Reset),
+                style::Reset,
+                pos = job.0 + 1,
+            );
+        }
+    }
+
+    save(&mut jobs, &filename, dry, quiet)
+}
+
+/// Process command on database and let user handle warnings, a missing message or an existing output file.
+///
+/// If policies are given the user won't be asked and warnings are handled by them instead.
+fn process<W: std::io::Write>(
+    w: &mut W,
+    jobs: &mut Jobs,
+    command: &mut Command,
+    checks: Checks,
+    policies: Option<&Policies>,
+    context: &Context,
+) -> Result<Operation, Error> {
+    let result = jobs.process(w, command, checks.clone(), context);
+    if let Some(policies) = policies {
+        return match result {
+            Err(Error::Warnings(warnings)) => {
+                apply(warnings, policies)?;
+                // process command again without checks which lead to warnings
+                jobs.process(w, command, checks.omit_warnings(), context)
+            }
+            result => result,
+        };
+    }
+    match result {
         Err(Error::Warnings(warnings)) => {
             // summarize
             if warnings.len() == 1 {
@@ -103,57 +365,149 @@ fn run<W: std::io::Write>(
                     return Err(Error::Cancel);
                 }
             }
-            // process command again without checks
-            match jobs.process(w, &command, Checks::omit(), context) {
+            // process command again without checks which lead to warnings
+            match jobs.process(w, command, checks.omit_warnings(), context) {
                 Err(Error::EnterMessage) => {
                     // still need to enter obligatory message
-                    command.set_message(enter(ASK_FOR_MESSAGE)?);
-                    jobs.process(w, &command, Checks::omit(), context)
+                    command.set_message(enter_message(jobs, command, context)?);
+                    jobs.process(w, command, checks.omit_warnings(), context)
                 }
-                Err(err) => return Err(err),
-                Ok(operation) => Ok(operation),
+                result => result,
             }
         }
         Err(Error::EnterMessage) => {
             // need message to finish
-            command.set_message(enter(ASK_FOR_MESSAGE)?);
-            jobs.process(w, &command, Checks::omit(), context)
+            command.set_message(enter_message(jobs, command, context)?);
+            jobs.process(w, command, checks.omit_warnings(), context)
         }
         Err(Error::OutputFileExists(filename)) => {
             eprintln!("{}", Error::OutputFileExists(filename));
             if ask("Do you want to overwrite the existing file?", false)? {
-                jobs.process(w, &command, Checks::omit(), context)
+                jobs.process(w, command, checks.omit_warnings(), context)
             } else {
                 eprintln!("No report generated.");
                 Ok(Operation::None)
             }
         }
-        Err(err) => return Err(err),
-        Ok(operation) => Ok(operation),
-    } {
-        eprintln!("{}", operation);
-        if !operation.reports_open_job() {
-            if let Some(job) = jobs.get_open_with_pos() {
+        result => result,
+    }
+}
+
+/// Handle warnings by policies and fail if any warning is not accepted.
+fn apply(warnings: Vec<Warning>, policies: &Policies) -> Result<(), Error> {
+    let mut failed = Vec::new();
+    for warning in warnings {
+        match policies.get(warning.check()) {
+            Policy::Fail => failed.push(warning),
+            Policy::Warn => eprintln!("\nWARNING: {warning}"),
+            Policy::Accept => (),
+        }
+    }
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        for (n, warning) in failed.iter().enumerate() {
+            eprintln!("\nWARNING {}) {}", n + 1, warning);
+        }
+        Err(Error::Warnings(failed))
+    }
+}
+
+/// Let user fill gaps by adding new jobs.
+fn fill<W: std::io::Write>(
+    w: &mut W,
+    jobs: &mut Jobs,
+    gaps: &[Gap],
+    checks: &Checks,
+    context: &Context,
+) -> Result<(), Error> {
+    for (n, gap) in gaps.iter().enumerate() {
+        eprintln!(
+            "\n{}{}Fill gap {}) {gap}?{}{}\n\
+            (b = like job before, a = like job after, n = with new message, anything else = skip)",
+            style::Bold,
+            Fg(Yellow),
+            n + 1,
+            Fg(Reset),
+            style::Reset
+        );
+        let mut buffer = String::new();
+        std::io::stdin().read_line(&mut buffer).map_err(Error::Io)?;
+        let template = match buffer.trim().to_lowercase().as_str() {
+            "b" if gap.before.is_some() => gap.before,
+            "a" if gap.after.is_some() => gap.after,
+            "n" => None,
+            _ => continue,
+        };
+        let (message, tags) = if let Some(pos) = template {
+            (
+                Some(jobs[pos].message.clone()),
+                Some(jobs[pos].tags.clone()),
+            )
+        } else {
+            (Some(None), None)
+        };
+        let mut command = Command::Add {
+            start: gap.start,
+            end: gap.end,
+            message,
+            tags,
+            billable: template.and_then(|pos| jobs[pos].billable),
+        };
+        eprintln!(
+            "{}",
+            process(w, jobs, &mut command, checks.clone(), None, context)?
+        );
+    }
+    Ok(())
+}
+
+/// Let user apply automated fixes of problems.
+fn repair<W: std::io::Write>(
+    w: &mut W,
+    jobs: &mut Jobs,
+    problems: &[Problem],
+    checks: &Checks,
+    context: &Context,
+) -> Result<(), Error> {
+    for (n, problem) in problems.iter().enumerate() {
+        if let Some(fix) = problem.fix() {
+            // a previous fix might have deleted the same job already
+            if matches!(fix, Fix::Delete(pos) if jobs[pos].is_deleted()) {
+                continue;
+            }
+            eprintln!("\nPROBLEM {}) {problem}", n + 1);
+            if ask(&format!("{fix}?"), false)? {
+                let mut command = fix.command();
                 eprintln!(
-                    "{}{}There is an open Job at position {pos}!{}{}",
-                    Fg(Yellow),
-                    style::Bold,
-                    Fg(Reset),
-                    style::Reset,
-                    pos = job.0 + 1,
+                    "{}",
+                    process(
+                        w,
+                        jobs,
+                        &mut command,
+                        checks.without_confirmation(),
+                        None,
+                        context
+                    )?
                 );
             }
         }
-    } else {
-        panic!("operation error")
-    };
+    }
+    Ok(())
+}
 
+/// Save database into file if it was modified (and if we do not run dry).
+fn save(jobs: &mut Jobs, filename: &str, dry: bool, quiet: bool) -> Result<(), Error> {
     if jobs.modified() {
         if dry {
-            eprintln!("DRY RUN: Changes were NOT saved into database file '{filename}'!");
+            if !quiet {
+                eprintln!("DRY RUN: Changes were NOT saved into database file '{filename}'!");
+            }
         } else {
-            jobs.save(&filename)?;
-            eprintln!("Saved database into file '{filename}'");
+            jobs.save(filename)?;
+            if !quiet {
+                eprintln!("Saved database into file '{filename}'");
+            }
         }
     }
     Ok(())
@@ -204,7 +558,11 @@ pub fn run_line_mut<W: std::io::Write>(
     checks: Checks,
     context: &Context,
 ) -> Result<Operation, Error> {
-    let command = parse(Args::parse_from(line.split_ascii_whitespace()), None, context)?;
+    let command = parse(
+        Args::parse_from(args::join_offsets(line.split_ascii_whitespace())),
+        None,
+        context,
+    )?;
     jobs.process(w, &command, checks, context)
 }
 
@@ -217,10 +575,21 @@ pub fn run_args_mut<W: std::io::Write>(
     checks: Checks,
     context: &Context,
 ) -> Result<Operation, Error> {
-    let command = parse(Args::parse_from(args), None, context)?;
+    let command = parse(
+        Args::parse_from(args::join_offsets(args.iter().copied())),
+        None,
+        context,
+    )?;
     jobs.process(w, &command, checks, context)
 }
 
+/// Get output as text without any ANSI color codes.
+fn plain(output: &[u8]) -> String {
+    let re = regex::Regex::new(r"\u{1b}\[([0-9]{1,2}(;[0-9]{1,2})*)?[m|K]").unwrap();
+    re.replace_all(&String::from_utf8_lossy(output), "")
+        .to_string()
+}
+
 /// Ask user on console a yes-no-question.
 fn ask(question: &str, default_yes: bool) -> Result<bool, Error> {
     eprintln!(
@@ -245,6 +614,102 @@ fn ask(question: &str, default_yes: bool) -> Result<bool, Error> {
     })
 }
 
+/// Let user pick one of the recent jobs as template.
+fn pick(jobs: &Jobs) -> Result<Template, Error> {
+    let templates = jobs.templates(9);
+    if templates.is_empty() {
+        return Err(Error::DatabaseEmpty);
+    }
+    eprintln!(
+        "{}{}Which job do you want to continue?{}{}\n",
+        style::Bold,
+        Fg(Yellow),
+        Fg(Reset),
+        style::Reset
+    );
+    for (n, pos) in templates.iter().enumerate() {
+        let job = &jobs[*pos];
+        eprintln!(
+            "{}) {} {}",
+            n + 1,
+            job.tags,
+            job.message
+                .as_ref()
+                .and_then(|message| message.lines().next())
+                .unwrap_or_default()
+        );
+    }
+
+    let mut buffer = String::new();
+    std::io::stdin().read_line(&mut buffer).map_err(Error::Io)?;
+
+    match buffer.trim().parse::<usize>() {
+        Ok(n) if n > 0 && n <= templates.len() => Ok(Template::Position(templates[n - 1])),
+        _ => Err(Error::Cancel),
+    }
+}
+
+/// Let user enter a message within an editor (if `VISUAL` or `EDITOR` is set) or on console.
+fn enter_message(jobs: &Jobs, command: &Command, context: &Context) -> Result<String, Error> {
+    let message = jobs.message_to_edit(command, context).unwrap_or_default();
+    if let Some(editor) = std::env::var("VISUAL")
+        .ok()
+        .or_else(|| std::env::var("EDITOR").ok())
+        .filter(|editor| !editor.trim().is_empty())
+    {
+        edit(&editor, &message)
+    } else {
+        enter(ASK_FOR_MESSAGE)
+    }
+}
+
+/// Line which separates the message from the instructions when editing a message.
+const SCISSORS: &str = "# ------------------------ >8 ------------------------";
+
+/// Edit a text with the given editor in a temporary file.
+/// Everything below the scissors line will be removed.
+fn edit(editor: &str, text: &str) -> Result<String, Error> {
+    use std::io::Write;
+    // file is created exclusively and readable by the user only
+    let mut file = tempfile::Builder::new()
+        .prefix("jobber-message-")
+        .suffix(".txt")
+        .tempfile()?;
+    write!(
+        file,
+        "{text}\n\
+        {SCISSORS}\n\
+        # Do not modify or remove the line above.\n\
+        # Enter a message about what you did to finish the job above it.\n\
+        # Everything below will be ignored and an empty message cancels.\n"
+    )?;
+    file.flush()?;
+
+    // editor may be given with arguments
+    let mut args = editor.split_whitespace();
+    let status = std::process::Command::new(args.next().unwrap_or_default())
+        .args(args)
+        .arg(file.path())
+        .status();
+    let result = std::fs::read_to_string(file.path());
+    if !status?.success() {
+        return Err(Error::Cancel);
+    }
+
+    let message = result?
+        .lines()
+        .take_while(|line| *line != SCISSORS)
+        .collect::<Vec<&str>>()
+        .join("\n")
+        .trim()
+        .to_string();
+    if message.is_empty() {
+        Err(Error::EnterMessage)
+    } else {
+        Ok(message)
+    }
+}
+
 /// Ask user for a multi line input.
 fn enter(question: &str) -> Result<String, Error> {
     eprintln!(
@@ -286,7 +751,51 @@ pub fn parse_line(line :&str,
     open_start: Option<DateTime>,
     context: &Context,
 ) -> Result<Command, Error> {
-    parse(Args::parse_from(line.split_whitespace()),open_start,context)
+    parse(
+        Args::parse_from(args::join_offsets(line.split_whitespace())),
+        open_start,
+        context,
+    )
+}
+
+/// Parse argument list into a command.
+/// # Arguments
+/// * `args` - arguments to parse (including program name)
+/// * `open_start` - if data base has an open job this shall give its starting time
+/// * `context` - reality
+#[cfg(test)]
+pub fn parse_args(
+    args: &[&str],
+    open_start: Option<DateTime>,
+    context: &Context,
+) -> Result<Command, Error> {
+    parse(
+        Args::parse_from(args::join_offsets(args.iter().copied())),
+        open_start,
+        context,
+    )
+}
+
+/// Collect which checks to enable or disable from the given comma separated lists.
+fn switch_checks(
+    enable: &Option<String>,
+    disable: &Option<String>,
+) -> Result<Option<BTreeMap<Check, bool>>, Error> {
+    if enable.is_none() && disable.is_none() {
+        return Ok(None);
+    }
+    let mut checks = BTreeMap::new();
+    if let Some(enable) = enable {
+        for check in Check::parse_list(enable)? {
+            checks.insert(check, true);
+        }
+    }
+    if let Some(disable) = disable {
+        for check in Check::parse_list(disable)? {
+            checks.insert(check, false);
+        }
+    }
+    Ok(Some(checks))
 }
 
 /// Parse arguments into a command.
@@ -322,6 +831,21 @@ pub fn parse(
     } else {
         None
     };
+    let switch = if let Some(switch) = args.switch {
+        Some(PartialDateTime::parse(switch)?)
+    } else {
+        None
+    };
+    let pause = if let Some(pause) = args.pause {
+        Some(PartialDateTime::parse(pause)?)
+    } else {
+        None
+    };
+    let resume = if let Some(resume) = args.resume {
+        Some(PartialDateTime::parse(resume)?)
+    } else {
+        None
+    };
     let duration = if let Some(duration) = args.duration {
         Some(Duration::parse(duration)?)
     } else {
@@ -329,6 +853,15 @@ pub fn parse(
     };
     let message = args.message;
     let tags = args.tags.map(|tags| TagSet::from(&tags));
+    let template = match args.like {
+        None => Template::Last,
+        Some(None) => Template::Pick,
+        Some(Some(like)) => match like.parse::<usize>() {
+            // de-humanize position
+            Ok(pos) if pos > 0 => Template::Position(pos - 1),
+            _ => Template::Search(like),
+        },
+    };
     let list = if let Some(list) = args.list {
         Some(Range::parse(list, context)?)
     } else {
@@ -339,6 +872,16 @@ pub fn parse(
     } else {
         None
     };
+    let doctor = args.doctor;
+    let remind = args.remind;
+    let status = args
+        .status
+        .map(|format| format.unwrap_or(STATUS_FORMAT.to_string()));
+    let gaps = if let Some(gaps) = args.gaps {
+        Some(Range::parse(gaps, context)?)
+    } else {
+        None
+    };
     let export = if let Some(export) = args.export {
         Some(Range::parse(export, context)?)
     } else {
@@ -350,6 +893,68 @@ pub fn parse(
     let resolution = args.resolution;
     let rate = args.rate;
     let max_hours = args.max_hours;
+    let billable = if args.billable {
+        Some(true)
+    } else if args.non_billable {
+        Some(false)
+    } else {
+        None
+    };
+    let min_hours = args.min_hours;
+    let rounding = if let Some(rounding) = args.rounding {
+        Some(Rounding::parse(&rounding)?)
+    } else {
+        None
+    };
+    let rounding_level = if let Some(rounding_level) = args.rounding_level {
+        Some(RoundingLevel::parse(&rounding_level)?)
+    } else {
+        None
+    };
+    let date_order = if let Some(date_order) = args.date_order {
+        Some(DateOrder::parse(&date_order)?)
+    } else {
+        None
+    };
+    let clock = if let Some(clock) = args.clock {
+        Some(Clock::parse(&clock)?)
+    } else {
+        None
+    };
+    let export_format = if let Some(export_format) = args.export_format {
+        check_export_format(&export_format)?;
+        Some(export_format)
+    } else {
+        None
+    };
+    let time_zone = if let Some(time_zone) = args.time_zone {
+        // check if time zone is valid
+        Zone::parse(&time_zone)?;
+        Some(time_zone)
+    } else {
+        None
+    };
+    let work_hours = if let Some(work_hours) = args.work_hours {
+        Some(WorkHours::parse(&work_hours)?)
+    } else {
+        None
+    };
+    let min_gap = if let Some(min_gap) = args.min_gap {
+        Some(Duration::parse(min_gap)?.num_minutes())
+    } else {
+        None
+    };
+    let day_boundary = if let Some(day_boundary) = args.day_boundary {
+        Some(
+            chrono::NaiveTime::parse_from_str(&day_boundary, "%H:%M")
+                .map_err(|_| Error::DayBoundaryFormat(day_boundary))?,
+        )
+    } else {
+        None
+    };
+    // store switched checks only if requested (otherwise they apply to this call only)
+    let checks =
+        switch_checks(&args.enable_checks, &args.disable_checks)?.filter(|_| args.save_checks);
     // true if any of the configuration items is available
     let configuration = args.configuration;
 
@@ -362,30 +967,79 @@ pub fn parse(
         None
     };
 
-    let edit = if let Some(edit) = args.edit {
-        if let Some(edit) = edit {
-            // de-humanize position
-            Some(Some(edit - 1))
+    let shift = if let Some(shift) = args.shift {
+        Some(if let Some(shift) = shift.strip_prefix('-') {
+            -Duration::parse(shift.to_string())?.num_minutes()
         } else {
-            Some(None)
-        }
+            Duration::parse(shift.trim_start_matches('+').to_string())?.num_minutes()
+        })
     } else {
         None
     };
+    let with_tags = args.with_tags.map(|tags| TagSet::from(&Some(tags)));
+    // edit a single job (by optional position) or many jobs within a range
+    let (edit, edit_many) = match args.edit {
+        Some(Some(edit)) => match edit.parse::<usize>() {
+            // de-humanize position
+            Ok(pos) if pos > 0 && shift.is_none() && with_tags.is_none() => {
+                (Some(Some(pos - 1)), None)
+            }
+            _ => (None, Some(Range::parse(Some(edit), context)?)),
+        },
+        // shift or filter the last job
+        Some(None) if shift.is_some() || with_tags.is_some() => (None, Some(Range::Count(1))),
+        Some(None) => (Some(None), None),
+        None => (None, None),
+    };
     let delete = if let Some(delete) = args.delete {
         Some(Range::parse(Some(delete), context)?)
     } else {
         None
     };
+    let merge = if let Some(merge) = args.merge {
+        Some(Range::parse(Some(merge), context)?)
+    } else {
+        None
+    };
+    let split = if let (Some(pos), Some(at)) = (args.split, args.at) {
+        // de-humanize position
+        Some((pos.max(1) - 1, PartialDateTime::parse(Some(at))?))
+    } else {
+        None
+    };
+    let restore = if let Some(restore) = args.restore {
+        Some(Range::parse(Some(restore), context)?)
+    } else {
+        None
+    };
+    let invoice = if let Some(invoice) = args.invoice {
+        Some((Range::parse(Some(invoice), context)?, args.reference))
+    } else if let Some(uninvoice) = args.uninvoice {
+        Some((Range::parse(Some(uninvoice), context)?, None))
+    } else {
+        None
+    };
 
     // 2) create command depending on what arguments were given...
 
-    Ok(if let Some(pos) = edit {
+    Ok(if let Some(range) = edit_many {
+        if start.is_some() || end.is_some() || duration.is_some() {
+            return Err(Error::EditManyTimes);
+        }
+        Command::EditMany {
+            range,
+            filter: with_tags,
+            tags,
+            message: message.flatten(),
+            shift,
+            billable,
+        }
+    } else if let Some(pos) = edit {
         if let Some(start) = start {
-            let mut start = start.into(context.time());
+            let mut start = start.into(context.time(), context);
             if let Some(end) = end {
                 if end == PartialDateTime::None {
-                    let end = end.into(context.time());
+                    let end = end.into(context.time(), context);
                     if end < start {
                         start -= Duration::days(1);
                     }
@@ -395,6 +1049,7 @@ pub fn parse(
                         end: EndOrDuration::End(end),
                         message,
                         tags,
+                        billable,
                     }
                 } else {
                     Command::Edit {
@@ -403,6 +1058,7 @@ pub fn parse(
                         end: EndOrDuration::None,
                         message,
                         tags,
+                        billable,
                     }
                 }
             } else if let Some(duration) = duration {
@@ -412,6 +1068,7 @@ pub fn parse(
                     end: EndOrDuration::Duration(duration),
                     message,
                     tags,
+                    billable,
                 }
             } else {
                 Command::Edit {
@@ -420,16 +1077,18 @@ pub fn parse(
                     end: EndOrDuration::None,
                     message,
                     tags,
+                    billable,
                 }
             }
         } else if let Some(end) = end {
-            let end = end.into(context.time());
+            let end = end.into(context.time(), context);
             Command::Edit {
                 pos,
                 start: None,
                 end: EndOrDuration::End(end),
                 message,
                 tags,
+                billable,
             }
         } else if let Some(duration) = duration {
             Command::Edit {
@@ -438,6 +1097,7 @@ pub fn parse(
                 end: EndOrDuration::Duration(duration),
                 message,
                 tags,
+                billable,
             }
         } else {
             Command::Edit {
@@ -446,15 +1106,33 @@ pub fn parse(
                 end: EndOrDuration::None,
                 message,
                 tags,
+                billable,
             }
         }
     } else if let Some(range) = delete {
         Command::Delete { range, tags }
+    } else if let Some(range) = restore {
+        Command::Restore { range, tags }
+    } else if let Some((range, reference)) = invoice {
+        Command::Invoice {
+            range,
+            tags,
+            reference,
+        }
+    } else if let Some(range) = merge {
+        Command::Merge { range, message }
+    } else if let Some((pos, at)) = split {
+        Command::Split {
+            pos,
+            at: at.into(context.time(), context),
+            message,
+            tags,
+        }
     } else if let Some(start) = start {
-        let mut start = start.into(context.time());
+        let mut start = start.into(context.time(), context);
         if let Some(end) = end {
             if end == PartialDateTime::None {
-                let end = end.into(context.time());
+                let end = end.into(context.time(), context);
                 if end < start {
                     start -= Duration::days(1);
                 }
@@ -463,9 +1141,10 @@ pub fn parse(
                     end,
                     message,
                     tags,
+                    billable,
                 }
             } else {
-                let mut end = end.into(start);
+                let mut end = end.into(start, context);
                 if end < start {
                     end += Duration::days(1);
                 }
@@ -474,6 +1153,7 @@ pub fn parse(
                     end,
                     message,
                     tags,
+                    billable,
                 }
             }
         } else if let Some(duration) = duration {
@@ -483,19 +1163,21 @@ pub fn parse(
                 end,
                 message,
                 tags,
+                billable,
             }
         } else {
             Command::Start {
                 start,
                 message,
                 tags,
+                billable,
             }
         }
     } else if let Some(start) = back {
-        let mut start = start.into(context.time());
+        let mut start = start.into(context.time(), context);
         if let Some(end) = end {
             if end == PartialDateTime::None {
-                let end = end.into(context.time());
+                let end = end.into(context.time(), context);
                 if end < start {
                     start -= Duration::days(1);
                 }
@@ -504,9 +1186,11 @@ pub fn parse(
                     end,
                     message,
                     tags,
+                    template,
+                    billable,
                 }
             } else {
-                let mut end = end.into(start);
+                let mut end = end.into(start, context);
                 if end < start {
                     end += Duration::days(1);
                 }
@@ -515,6 +1199,8 @@ pub fn parse(
                     end,
                     message,
                     tags,
+                    template,
+                    billable,
                 }
             }
         } else if let Some(duration) = duration {
@@ -524,25 +1210,59 @@ pub fn parse(
                 end,
                 message,
                 tags,
+                template,
+                billable,
             }
         } else {
             Command::Back {
                 start,
                 message,
                 tags,
+                template,
+                billable,
             }
         }
     } else if let Some(end) = end {
         let end = if PartialDateTime::None == end {
             context.time()
         } else {
-            end.into(if let Some(open_start) = open_start {
-                open_start
-            } else {
-                context.time()
-            })
+            end.into(
+                if let Some(open_start) = open_start {
+                    open_start
+                } else {
+                    context.time()
+                },
+                context,
+            )
         };
         Command::End { end, message, tags }
+    } else if let Some(switch) = switch {
+        let time = if PartialDateTime::None == switch {
+            context.time()
+        } else {
+            switch.into(open_start.unwrap_or(context.time()), context)
+        };
+        Command::Switch {
+            time,
+            message,
+            tags,
+            end_message: None,
+            billable,
+        }
+    } else if let Some(pause) = pause {
+        let time = if PartialDateTime::None == pause {
+            context.time()
+        } else {
+            pause.into(open_start.unwrap_or(context.time()), context)
+        };
+        Command::Pause { time }
+    } else if let Some(resume) = resume {
+        let time = if PartialDateTime::None == resume {
+            context.time()
+        } else {
+            resume.into(open_start.unwrap_or(context.time()), context)
+        };
+        Command::Resume { time }
     } else if let Some(range) = list {
         Command::List { range, tags }
     } else if let Some(range) = export {
@@ -551,17 +1271,54 @@ pub fn parse(
             tags,
             columns: csv,
         }
+    } else if doctor {
+        Command::Doctor
+    } else if remind {
+        Command::Remind
+    } else if let Some(format) = status {
+        Command::Status { format }
+    } else if let Some(range) = gaps {
+        Command::Gaps { range, tags }
     } else if let Some(range) = report {
         Command::Report { range, tags }
     } else if configuration {
         Command::ShowConfiguration
-    } else if resolution.is_some() || rate.is_some() || max_hours.is_some() {
+    } else if resolution.is_some()
+        || rate.is_some()
+        || max_hours.is_some()
+        || min_hours.is_some()
+        || billable.is_some()
+        || rounding.is_some()
+        || rounding_level.is_some()
+        || date_order.is_some()
+        || clock.is_some()
+        || export_format.is_some()
+        || time_zone.is_some()
+        || work_hours.is_some()
+        || min_gap.is_some()
+        || checks.is_some()
+        || day_boundary.is_some()
+    {
         Command::SetConfiguration {
             tags,
             update: Properties {
                 resolution,
                 rate,
                 max_hours,
+                min_hours,
+                billable,
+                rounding,
+                rounding_level,
+            },
+            settings: Settings {
+                date_order,
+                clock,
+                export_format,
+                time_zone,
+                work_hours,
+                min_gap,
+                checks,
+                day_boundary,
             },
         }
     } else if let Some(filename) = legacy_import {
@@ -0,0 +1,61 @@
+//! Named profiles which each have their own database.
+
+use jobberdb::prelude::*;
+
+/// Report the jobs of several profiles and sum up their hours.
+///
+/// Profiles whose database does not exist are skipped.
+/// # Arguments
+/// * `profiles` - names and database files of the profiles to report
+/// * `command` - get report command (called with the settings of each profile's database)
+/// * `context` - reality
+pub fn report<W: std::io::Write>(
+    w: &mut W,
+    profiles: &[(String, String)],
+    command: impl Fn() -> Result<Command, Error>,
+    context: &Context,
+) -> Result<(), Error> {
+    let mut summaries = Vec::new();
+    let mut count = 0;
+    let mut hours = 0.0;
+    let mut pay: Option<f64> = None;
+    for (name, filename) in profiles {
+        let mut jobs = match Jobs::load(filename) {
+            Ok(jobs) => jobs,
+            Err(Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+                writeln!(w, "Profile '{name}' ({filename}): database not found\n")?;
+                summaries.push((name, "database not found".to_string()));
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        writeln!(w, "Profile '{name}' ({filename}):\n")?;
+        // parse and print calendar with the settings of this profile
+        locale::init(&jobs.configuration.settings);
+        let positions = match jobs.process(w, &command()?, Checks::all(), context)? {
+            Operation::Report(positions, _, _) => positions,
+            _ => return Err(Error::ProfilesWithoutReport),
+        };
+        let list = jobs.list(&positions, context);
+        count += list.len();
+        hours += list.hours_overall(context);
+        if let Some(p) = list.pay_overall(context) {
+            pay = Some(pay.unwrap_or_default() + p);
+        }
+        summaries.push((name, list.summary(context)));
+    }
+    // sum up all profiles
+    for (name, summary) in summaries {
+        writeln!(w, "{name}: {summary}")?;
+    }
+    write!(
+        w,
+        "All profiles: {count} job(s), {} hours",
+        format::hours_pure(hours)
+    )?;
+    if let Some(pay) = pay {
+        write!(w, " = ${}", format::pay_pure(pay))?;
+    }
+    writeln!(w)?;
+    Ok(())
+}
@@ -19,7 +19,8 @@ fn test_add() {
             start: "2023-2-1 12:00".into(),
             end: "2023-2-1 13:00".into(),
             message: None,
-            tags: None
+            tags: None,
+            billable: None,
         }
     );
 
@@ -30,7 +31,8 @@ fn test_add() {
             start: "2023-2-1 23:00".into(),
             end: "2023-2-2 1:00".into(),
             message: None,
-            tags: None
+            tags: None,
+            billable: None,
         }
     );
 
@@ -41,7 +43,8 @@ fn test_add() {
             start: "2023-1-31 23:00".into(),
             end: "2023-2-1 12:00".into(),
             message: None,
-            tags: None
+            tags: None,
+            billable: None,
         }
     );
 }
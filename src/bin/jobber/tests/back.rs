@@ -184,3 +184,85 @@ fn test_back_to_work_edit() {
     assert!(jobs[1].tags.contains(&"tag2".into()));
     assert!(jobs[1].tags.contains(&"tag3".into()));
 }
+
+/// Continue work by using an older job as template.
+///
+/// - [x] check argument parsing
+/// - [x] check database modification
+/// - [ ] check output
+///
+#[test]
+fn test_back_to_work_like() {
+    let context = Context::new_test("2023-2-1 12:00");
+
+    assert_eq!(
+        parse_line("jobber -b 11:00 --like 2", None, &context).unwrap(),
+        Command::Back {
+            start: "2023-2-1 11:00".into(),
+            message: None,
+            tags: None,
+            template: Template::Position(1),
+            billable: None,
+        }
+    );
+    assert_eq!(
+        parse_line("jobber -b 11:00 --like", None, &context)
+            .unwrap()
+            .template(),
+        Some(&Template::Pick)
+    );
+
+    // add some jobs
+    let mut jobs = Jobs::new();
+    for line in [
+        "jobber -s 1/30,8:00 -e 10:00 -m writing-docs -t docs",
+        "jobber -s 1/30,10:00 -e 12:00 -m fixing-bugs -t code",
+        "jobber -s 1/31,8:00 -e 12:00 -m more-docs -t docs",
+        "jobber -s 1/31,13:00 -e 17:00 -m fixing-bugs -t code",
+    ] {
+        run_line_mut(
+            &mut std::io::stdout(),
+            line,
+            &mut jobs,
+            Checks::all_but(Check::UnknownTags),
+            &context,
+        )
+        .unwrap();
+    }
+    assert_eq!(jobs.templates(9), vec![3, 2, 0]);
+
+    // continue job at position
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber -b 8:00 -e 9:00 --like 1",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs[4].message, jobs[0].message);
+    assert_eq!(jobs[4].tags, jobs[0].tags);
+
+    // continue latest job with a message containing a term
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber -b 9:00 -e 10:00 --like MORE",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs[5].message, jobs[2].message);
+
+    // nothing found
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber -b 10:00 --like nothing",
+            &mut jobs,
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::TemplateNotFound(_))
+    ));
+}
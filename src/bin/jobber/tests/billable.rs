@@ -0,0 +1,111 @@
+//! Testing options `--billable` and `--non-billable`.
+
+use crate::*;
+
+/// Mark jobs and tags as billable or non-billable.
+///
+/// - [x] check argument parsing
+/// - [x] check database modification
+/// - [x] check output
+///
+#[test]
+fn test_billable() {
+    let context = Context::new_test("2023-2-3 12:00");
+
+    assert_eq!(
+        parse_line("jobber -s 8:00 -m x --non-billable", None, &context).unwrap(),
+        Command::Start {
+            start: "2023-2-3 8:00".into(),
+            message: Some(Some("x".into())),
+            tags: None,
+            billable: Some(false),
+        }
+    );
+    assert!(matches!(
+        parse_line("jobber --non-billable -t internal", None, &context).unwrap(),
+        Command::SetConfiguration {
+            update: Properties {
+                billable: Some(false),
+                ..
+            },
+            ..
+        }
+    ));
+
+    // client work is paid but internal work is not by default
+    let mut jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber --rate 100 -t client",
+        None,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    for line in [
+        "jobber --non-billable -t internal",
+        "jobber -s 2/1/2023,8:00 -e 10:00 -m work -t client",
+        "jobber -s 2/1/2023,10:00 -e 11:00 -m meeting -t client --non-billable",
+        "jobber -s 2/1/2023,11:00 -e 12:00 -m cleanup -t internal",
+        "jobber -s 2/1/2023,12:00 -e 13:00 -m bugfix -t internal --billable",
+    ] {
+        run_line_mut(
+            &mut std::io::stdout(),
+            line,
+            &mut jobs,
+            Checks::all_but(Check::UnknownTags),
+            &context,
+        )
+        .unwrap();
+    }
+    assert_eq!(jobs[1].billable, Some(false));
+    assert_eq!(jobs[2].billable, None);
+
+    let list = jobs.all(&context);
+    assert_eq!(list.hours_overall(&context), 5.0);
+    assert_eq!(list.hours_billable(true, &context), 3.0);
+    assert_eq!(list.hours_billable(false, &context), 2.0);
+    assert_eq!(list.pay_overall(&context), Some(200.0));
+
+    // change flag by editing
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --edit 2 --billable",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs.all(&context).pay_overall(&context), Some(300.0));
+
+    let mut output = Vec::new();
+    run_line_mut(
+        &mut output,
+        "jobber -E --csv message,billable,pay -t client",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "\"Message\",\"Billable\",\"Pay\"\n\"work\",\"yes\",200\n\"meeting\",\"yes\",100\n"
+    );
+    // flags also apply when going back to work or switching
+    for line in [
+        "jobber -b 2/2/2023,8:00 -e 9:00 --like 1 --non-billable",
+        "jobber -b 2/2/2023,9:00 --like 1 --non-billable",
+        "jobber --switch 2/2/2023,10:00 -m more --billable",
+    ] {
+        run_line_mut(
+            &mut std::io::sink(),
+            line,
+            &mut jobs,
+            Checks::all(),
+            &context,
+        )
+        .unwrap();
+    }
+    assert_eq!(jobs[4].billable, Some(false));
+    assert_eq!(jobs[5].billable, Some(false));
+    assert_eq!(jobs[6].billable, Some(true));
+}
@@ -0,0 +1,101 @@
+//! Testing options `--enable-checks`, `--disable-checks` and `--save-checks`.
+
+use crate::*;
+
+/// Enable or disable checks persistently or for a single call.
+///
+/// - [x] check argument parsing
+/// - [x] check database modification
+/// - [x] check switching of checks
+///
+#[test]
+fn test_checks() {
+    let context = Context::new_test("2023-2-1 12:00");
+
+    assert!(matches!(
+        parse_line("jobber --disable-checks overlapping", None, &context),
+        Err(Error::UnknownCheck(check)) if check == "overlapping"
+    ));
+
+    assert!(Args::try_parse_from(["jobber", "--save-checks"]).is_err());
+    assert!(Args::try_parse_from([
+        "jobber",
+        "--save-checks",
+        "--disable-checks",
+        "overlaps",
+        "-l"
+    ])
+    .is_err());
+
+    // switches without --save-checks are not stored
+    let jobs = run_line(
+        &mut std::io::sink(),
+        "jobber -s 2/1/2023,8:00 -e 9:00 -m work --disable-checks overlaps",
+        None,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert!(jobs.configuration.settings.checks.is_none());
+
+    // store switches in database
+    let mut jobs = Jobs::new();
+    run_line_mut(
+        &mut std::io::sink(),
+        "jobber --disable-checks overlaps,unknown-tags --save-checks",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    run_line_mut(
+        &mut std::io::sink(),
+        "jobber --enable-checks unknown-tags --save-checks",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    let configured = jobs.configuration.settings.checks.clone().unwrap();
+    assert_eq!(
+        configured,
+        [(Check::Overlaps, false), (Check::UnknownTags, true)].into()
+    );
+
+    // switch checks
+    let checks = Checks::all().switch(&configured);
+    assert!(!checks.has(Check::Overlaps));
+    assert!(checks.has(Check::UnknownTags));
+    assert!(checks.has(Check::ConfirmDeletion));
+    let checks = checks.switch(&[(Check::Overlaps, true)].into());
+    assert!(checks.has(Check::Overlaps));
+
+    // overlapping jobs are accepted without the check
+    run_line_mut(
+        &mut std::io::sink(),
+        "jobber -s 2/1/2023,8:00 -e 10:00 -m work",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::sink(),
+            "jobber -s 2/1/2023,9:00 -e 11:00 -m work",
+            &mut jobs,
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::Warnings(_))
+    ));
+    run_line_mut(
+        &mut std::io::sink(),
+        "jobber -s 2/1/2023,9:00 -e 11:00 -m work",
+        &mut jobs,
+        Checks::all().switch(&configured),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs.count(), 2);
+}
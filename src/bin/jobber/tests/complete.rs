@@ -0,0 +1,85 @@
+//! Testing option `--completions` and dynamic completion.
+
+use crate::complete::*;
+use crate::*;
+use std::ffi::{OsStr, OsString};
+
+/// Generate completion scripts and complete comma separated lists.
+///
+/// - [x] check argument parsing
+/// - [x] check output
+/// - [x] check completion of lists
+/// - [x] check completion of tags from a given database
+///
+#[test]
+fn test_complete() {
+    let context = Context::new_test("2023-2-1 12:00");
+
+    // generate scripts
+    for shell in ["bash", "fish", "zsh"] {
+        let mut output = Vec::new();
+        run(
+            &mut output,
+            Args::parse_from(["jobber", "--completions", shell]),
+            Checks::all(),
+            &context,
+        )
+        .unwrap();
+        assert!(String::from_utf8(output).unwrap().contains(COMPLETE_VAR));
+    }
+    assert!(matches!(
+        run(
+            &mut std::io::sink(),
+            Args::parse_from(["jobber", "--completions", "cmd"]),
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::UnknownShell(shell)) if shell == "cmd"
+    ));
+
+    // complete the last item of comma separated lists
+    let complete = |current: &str| {
+        complete_list(OsStr::new(current), ["one", "two", "three"].into_iter())
+            .iter()
+            .map(|candidate| candidate.get_value().to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(complete(""), ["one", "two", "three"]);
+    assert_eq!(complete("t"), ["two", "three"]);
+    assert_eq!(complete("two,t"), ["two,three"]);
+    assert_eq!(complete("one,"), ["one,two", "one,three"]);
+
+    // all completed columns can be exported
+    for column in COLUMNS {
+        assert!(Column::from(column).is_ok());
+    }
+
+    // complete tags from the database given at the command line
+    let mut jobs = run_line(
+        &mut std::io::sink(),
+        "jobber -s 8:00 -e 9:00 -m tagged -t alpha,beta,other",
+        None,
+        Checks::omit(),
+        &context,
+    )
+    .unwrap();
+    let file = tempfile::NamedTempFile::new().unwrap();
+    let filename = file.path().to_string_lossy().to_string();
+    jobs.save(&filename).unwrap();
+    let complete = |line: &str, current: &str| {
+        let args = line.split(' ').map(OsString::from).collect::<Vec<_>>();
+        complete_tags_of(args.into_iter(), OsStr::new(current))
+            .iter()
+            .map(|candidate| candidate.get_value().to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+    };
+    for option in ["-f", "--filename"] {
+        let line = format!("jobber -- jobber {option} {filename} -t");
+        assert_eq!(complete(&line, "a"), ["alpha"]);
+        assert_eq!(complete(&line, "alpha,"), ["alpha,beta", "alpha,other"]);
+    }
+    assert_eq!(
+        complete(&format!("jobber -- jobber --filename={filename} -t"), "b"),
+        ["beta"]
+    );
+}
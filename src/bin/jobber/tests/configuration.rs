@@ -0,0 +1,319 @@
+//! Testing configuration options.
+
+use super::clean;
+use crate::*;
+
+/// Change date and time format settings.
+///
+/// - [x] check argument parsing
+/// - [x] check database modification
+/// - [ ] check output
+///
+#[test]
+fn test_settings() {
+    let context = Context::new_test("2023-2-1 12:00");
+
+    assert_eq!(
+        parse_line("jobber --date-order dmy --clock 12", None, &context).unwrap(),
+        Command::SetConfiguration {
+            tags: None,
+            update: Properties {
+                resolution: None,
+                rate: None,
+                max_hours: None,
+                min_hours: None,
+                billable: None,
+                rounding: None,
+                rounding_level: None,
+            },
+            settings: Settings {
+                date_order: Some(DateOrder::DMY),
+                clock: Some(Clock::H12),
+                export_format: None,
+                time_zone: None,
+                work_hours: None,
+                min_gap: None,
+                checks: None,
+                day_boundary: None,
+            }
+        }
+    );
+
+    assert!(matches!(
+        parse_line("jobber --date-order dym", None, &context),
+        Err(Error::DateOrderFormat(_))
+    ));
+    assert!(matches!(
+        parse_line("jobber --clock 13", None, &context),
+        Err(Error::ClockFormat(_))
+    ));
+    assert!(matches!(
+        parse_line("jobber --export-format %Y-%m-%d,%Q", None, &context),
+        Err(Error::ExportFormat(format)) if format == "%Y-%m-%d,%Q"
+    ));
+    assert!(matches!(
+        parse_line("jobber --export-format %d.%m.%Y,%H:%M", None, &context),
+        Ok(Command::SetConfiguration {
+            settings: Settings {
+                export_format: Some(_),
+                ..
+            },
+            ..
+        })
+    ));
+    assert!(matches!(
+        parse_line("jobber --time-zone Mars/Olympus", None, &context),
+        Err(Error::TimeZoneFormat(_))
+    ));
+    assert!(matches!(
+        parse_line("jobber --work-hours 17:00-8:00", None, &context),
+        Err(Error::WorkHoursFormat(_))
+    ));
+    assert!(matches!(
+        parse_line("jobber --time-zone Europe/Berlin", None, &context),
+        Ok(Command::SetConfiguration {
+            settings: Settings {
+                time_zone: Some(_),
+                ..
+            },
+            ..
+        })
+    ));
+
+    let jobs = run_args(
+        &mut std::io::stdout(),
+        &[
+            "jobber",
+            "--date-order",
+            "ymd",
+            "--export-format",
+            "%Y%m%d %H%M",
+        ],
+        None,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert!(jobs.modified());
+    assert_eq!(jobs.configuration.settings.date_order, Some(DateOrder::YMD));
+    assert_eq!(jobs.configuration.settings.clock, None);
+    assert_eq!(
+        jobs.configuration.settings.export_format,
+        Some("%Y%m%d %H%M".into())
+    );
+    // base properties stay untouched
+    assert_eq!(jobs.configuration.base, Properties::default());
+
+    // changed settings take effect immediately but only while processing that database
+    assert_eq!(locale::date_order(), DateOrder::YMD);
+    run_line(
+        &mut std::io::sink(),
+        "jobber -l",
+        None,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(locale::date_order(), DateOrder::MDY);
+
+    // time zone is parsed when the register is initialized
+    run_line(
+        &mut std::io::sink(),
+        "jobber --time-zone Europe/Berlin",
+        None,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(locale::zone(), Zone::parse("Europe/Berlin").unwrap());
+
+    // jobs are listed with the settings of their own database
+    let twelve = run_line(
+        &mut std::io::sink(),
+        "jobber --clock 12",
+        None,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    let twelve = run_line(
+        &mut std::io::sink(),
+        "jobber -s 2/1/2023,14:00 -e 15:00 -m work",
+        Some(twelve),
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    run_line(
+        &mut std::io::sink(),
+        "jobber --clock 24",
+        None,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(locale::clock(), Clock::H24);
+    assert!(twelve.all(&context).to_string().contains("02:00 PM"));
+}
+
+/// Change rounding and rounding level.
+///
+/// - [x] check argument parsing
+/// - [x] check database modification
+/// - [ ] check output
+///
+#[test]
+fn test_rounding() {
+    let context = Context::new_test("2023-2-3 12:00");
+
+    assert!(matches!(
+        parse_line("jobber --rounding sideways", None, &context),
+        Err(Error::RoundingFormat(_))
+    ));
+    assert!(matches!(
+        parse_line("jobber --rounding-level week", None, &context),
+        Err(Error::RoundingLevelFormat(_))
+    ));
+
+    // two jobs of 20 minutes at one day and one at the next day
+    let mut jobs = Jobs::new();
+    for line in [
+        "jobber -s 2/1/2023,8:00 -e 8:20 -m a",
+        "jobber -s 2/1/2023,9:00 -e 9:20 -m b",
+        "jobber -s 2/2/2023,8:00 -e 8:20 -m c",
+    ] {
+        run_line_mut(
+            &mut std::io::stdout(),
+            line,
+            &mut jobs,
+            Checks::all(),
+            &context,
+        )
+        .unwrap();
+    }
+    let mut hours = |line: &str| {
+        run_line_mut(
+            &mut std::io::stdout(),
+            line,
+            &mut jobs,
+            Checks::all(),
+            &context,
+        )
+        .unwrap();
+        jobs.all(&context).hours_overall(&context)
+    };
+
+    // default is rounding up per job
+    assert_eq!(hours("jobber --resolution 0.25"), 1.5);
+    assert_eq!(hours("jobber --rounding nearest"), 0.75);
+    assert_eq!(hours("jobber --rounding down"), 0.75);
+    assert_eq!(hours("jobber --rounding up --rounding-level day"), 1.25);
+    assert_eq!(hours("jobber --rounding-level invoice"), 1.0);
+    assert_eq!(hours("jobber --rounding down"), 1.0);
+    assert_eq!(hours("jobber --rounding-level day"), 0.75);
+}
+
+/// Set minimum billable hours.
+///
+/// - [x] check argument parsing
+/// - [x] check database modification
+/// - [x] check output
+///
+#[test]
+fn test_min_hours() {
+    let context = Context::new_test("2023-2-3 12:00");
+
+    let mut jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber --min-hours 0.5 -t support",
+        None,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs.configuration.tags["support"].min_hours, Some(0.5));
+
+    // a short call and a longer one
+    for line in [
+        "jobber -s 2/1/2023,8:00 -e 8:05 -m call -t support",
+        "jobber -s 2/1/2023,9:00 -e 10:00 -m call -t support",
+    ] {
+        run_line_mut(
+            &mut std::io::stdout(),
+            line,
+            &mut jobs,
+            Checks::all_but(Check::UnknownTags),
+            &context,
+        )
+        .unwrap();
+    }
+    assert_eq!(
+        jobs[0].hours(&jobs.configuration.tags["support"], &context),
+        0.5
+    );
+    assert_eq!(jobs.all(&context).hours_overall(&context), 1.5);
+
+    // export shows worked and billed hours side by side
+    let mut output = Vec::new();
+    run_line_mut(
+        &mut output,
+        "jobber -E --csv hours,raw-hours",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "\"Hours\",\"Raw.Hours\"\n0.5,0.08\n1,1\n"
+    );
+
+    // minimum hours apply once to a job which passes over midnight
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber -s 2/1/2023,23:55 -e 2/2/2023,0:05 -m call -t support",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    let mut output = Vec::new();
+    run_line_mut(&mut output, "jobber -r", &mut jobs, Checks::all(), &context).unwrap();
+    let output = clean(&output);
+    assert!(output.contains("1.92    0.08"));
+    assert!(output.contains("Feb 2023: 2 hours"));
+    assert!(output.contains("Total: 3 job(s), 2 hours"));
+}
+
+/// Set the day boundary.
+///
+/// - [x] check argument parsing
+/// - [x] check database modification
+/// - [x] check output
+///
+#[test]
+fn test_day_boundary() {
+    let context = Context::new_test("2023-2-3 12:00");
+
+    assert!(matches!(
+        parse_line("jobber --day-boundary 25:00", None, &context),
+        Err(Error::DayBoundaryFormat(_))
+    ));
+
+    let jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber --day-boundary 4:00",
+        None,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(
+        jobs.configuration.settings.day_boundary,
+        chrono::NaiveTime::from_hms_opt(4, 0, 0)
+    );
+    assert_eq!(
+        jobs.configuration.settings.to_string(),
+        "Day boundary: 04:00\n"
+    );
+}
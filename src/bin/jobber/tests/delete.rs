@@ -114,3 +114,129 @@ Total: 1 job(s), 1.5 hours
         .to_string()
     );
 }
+
+/// Delete jobs and restore them.
+///
+/// - [x] check argument parsing
+/// - [x] check database modification
+/// - [x] check restoring overlapping or open jobs
+/// - [ ] check output
+///
+#[test]
+fn test_restore() {
+    let context = Context::new_test("2023-2-1 12:00");
+    let mut jobs = Jobs::new();
+
+    // add two jobs and delete both
+    for line in [
+        "jobber -s 8:00 -e 10:30 -m first-job",
+        "jobber -s 11:00 -e 12:30 -m second-job",
+        "jobber --delete 1-2",
+    ] {
+        run_line_mut(
+            &mut std::io::stdout(),
+            line,
+            &mut jobs,
+            Checks::omit(),
+            &context,
+        )
+        .unwrap();
+    }
+    assert_eq!(jobs.count(), 0);
+
+    // restore second job
+    assert_eq!(
+        parse_line("jobber --restore 2", None, &context).unwrap(),
+        Command::Restore {
+            range: Range::At(vec![1]),
+            tags: None
+        }
+    );
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --restore 2",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs.count(), 1);
+    assert!(!jobs[1].is_deleted());
+
+    // add a job which overlaps the first one which then can not be restored without warning
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber -s 9:00 -e 10:00 -m third-job",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --restore 1",
+            &mut jobs,
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::Warnings(_))
+    ));
+    assert!(jobs[0].is_deleted());
+
+    // restored jobs are checked against each other
+    let mut jobs = Jobs::new();
+    for line in [
+        "jobber -s 8:00 -e 10:00 -m first-job",
+        "jobber -s 9:00 -e 11:00 -m second-job",
+        "jobber --delete 1-2",
+    ] {
+        run_line_mut(
+            &mut std::io::sink(),
+            line,
+            &mut jobs,
+            Checks::omit(),
+            &context,
+        )
+        .unwrap();
+    }
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::sink(),
+            "jobber --restore 1-2",
+            &mut jobs,
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::Warnings(warnings)) if warnings.iter().all(|w| w.check() == Check::Overlaps)
+    ));
+    assert_eq!(jobs.count(), 0);
+
+    // a deleted open job can not be restored while another job is open
+    let mut jobs = Jobs::new();
+    for line in [
+        "jobber -s 8:00 -m first-job",
+        "jobber --delete 1",
+        "jobber -s 9:00 -m second-job",
+    ] {
+        run_line_mut(
+            &mut std::io::sink(),
+            line,
+            &mut jobs,
+            Checks::omit(),
+            &context,
+        )
+        .unwrap();
+    }
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::sink(),
+            "jobber --restore 1",
+            &mut jobs,
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::OpenJob(1, _))
+    ));
+    assert!(jobs[0].is_deleted());
+}
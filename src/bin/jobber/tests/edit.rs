@@ -1,5 +1,6 @@
 //! Testing option `--edit`.
 
+use super::clean;
 use crate::*;
 
 /// Edit several items of jobs in a database.
@@ -243,3 +244,118 @@ fn test_edit_last() {
     assert!(jobs[1].tags.contains(&"tag3".into()));
     assert!(jobs[1].tags.contains(&"tag4".into()));
 }
+
+/// Edit multiple jobs at once.
+///
+/// - [x] checks argument parsing
+/// - [x] check database modification
+/// - [ ] check output
+///
+#[test]
+fn test_edit_many() {
+    let context = Context::new_test("2023-2-1 12:00");
+
+    assert_eq!(
+        parse_line(
+            "jobber --edit 1-3 --with-tags a -t +c --shift -0:30",
+            None,
+            &context
+        )
+        .unwrap(),
+        Command::EditMany {
+            range: Range::PositionRange(0, 2),
+            filter: Some(TagSet::from("a")),
+            tags: Some(TagSet::from("+c")),
+            message: None,
+            shift: Some(-30),
+            billable: None,
+        }
+    );
+    assert!(matches!(
+        parse_line("jobber --edit 1-3 -s 8:00", None, &context),
+        Err(Error::EditManyTimes)
+    ));
+
+    // add some jobs
+    let mut jobs = Jobs::new();
+    for line in [
+        "jobber -s 8:00 -e 9:00 -m first -t a",
+        "jobber -s 9:00 -e 10:00 -m second -t b",
+        "jobber -s 10:00 -e 11:00 -m third -t a",
+    ] {
+        run_line_mut(
+            &mut std::io::stdout(),
+            line,
+            &mut jobs,
+            Checks::omit(),
+            &context,
+        )
+        .unwrap();
+    }
+
+    // modification needs to be confirmed
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --edit 1-3 --with-tags a -m changed",
+            &mut jobs,
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::Warnings(warnings)) if matches!(warnings[0], Warning::ConfirmModification(_))
+    ));
+    assert_eq!(jobs[0].message, Some("first".into()));
+
+    // change message and tags of all jobs with tag `a`
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --edit 1-3 --with-tags a -m changed -t +c",
+        &mut jobs,
+        Checks::no_confirm(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs[0].message, Some("changed".into()));
+    assert_eq!(jobs[1].message, Some("second".into()));
+    assert_eq!(jobs[2].message, Some("changed".into()));
+    assert!(jobs[2].tags.contains(&"a".into()));
+    assert!(jobs[2].tags.contains(&"c".into()));
+
+    // changes are shown even without confirmation
+    let operation = run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --edit 2-3 --with-tags b -m updated",
+        &mut jobs,
+        Checks::omit(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(
+        clean(&operation.to_string().into_bytes()),
+        "Modified job(s) at position(s): 2\n\n    Pos: 2\n-Message: second\n+Message: updated\n"
+    );
+
+    // shifting all jobs does not overlap
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --edit 1- --shift 0:30",
+        &mut jobs,
+        Checks::no_confirm(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs[0].start, "2023-2-1 8:30".into());
+    assert_eq!(jobs[2].end, Some("2023-2-1 11:30".into()));
+
+    // shifting some jobs does
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --edit 2 --shift -0:15",
+            &mut jobs,
+            Checks::no_confirm(),
+            &context,
+        ),
+        Err(Error::Warnings(warnings)) if matches!(warnings[0], Warning::Overlaps { .. })
+    ));
+}
@@ -0,0 +1,64 @@
+//! Testing message input with an editor.
+
+use crate::*;
+
+/// Edit messages with some commands which act like an editor.
+#[test]
+fn test_editor() {
+    // editor which does not change the pre-filled text
+    assert_eq!(edit("true", "some message").unwrap(), "some message");
+
+    // editor which changes the text
+    assert_eq!(
+        edit("sed -i s/some/other/", "some message\nin two lines").unwrap(),
+        "other message\nin two lines"
+    );
+
+    // lines starting with '#' are kept
+    assert_eq!(
+        edit("true", "#1 fixed\n# and more").unwrap(),
+        "#1 fixed\n# and more"
+    );
+
+    // empty message
+    assert!(matches!(edit("true", ""), Err(Error::EnterMessage)));
+
+    // editor fails
+    assert!(matches!(edit("false", "some message"), Err(Error::Cancel)));
+}
+
+/// Get the message which shall be pre-filled.
+#[test]
+fn test_message_to_edit() {
+    let context = Context::new_test("2023-2-1 12:00");
+
+    let jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber -s 8:00 -e 9:00 -m first",
+        None,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    let jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber -s 10:00 -m second",
+        Some(jobs),
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+
+    let command = parse_line("jobber --edit 1 -m", None, &context).unwrap();
+    assert_eq!(
+        jobs.message_to_edit(&command, &context),
+        Some("first".into())
+    );
+    let command = parse_line("jobber -e -m", None, &context).unwrap();
+    assert_eq!(
+        jobs.message_to_edit(&command, &context),
+        Some("second".into())
+    );
+    let command = parse_line("jobber -s -m", None, &context).unwrap();
+    assert_eq!(jobs.message_to_edit(&command, &context), None);
+}
@@ -39,3 +39,51 @@ fn test_csv_date() {
         .to_string()
     );
 }
+
+/// Export hours rounded at the configured rounding level.
+///
+/// - [x] checks argument parsing
+/// - [ ] check database modification
+/// - [x] check output
+///
+#[test]
+fn test_csv_rounded_hours() {
+    let context = Context::new_test("2023-2-3 12:00");
+    let mut jobs = Jobs::new();
+    for line in [
+        "jobber --rounding-level day",
+        "jobber -s 2/1/2023,8:00 -e 8:05 -m one",
+        "jobber -s 2/1/2023,9:00 -e 9:05 -m two",
+        "jobber -s 2/2/2023,8:00 -e 8:05 -m three",
+    ] {
+        run_line_mut(
+            &mut std::io::stdout(),
+            line,
+            &mut jobs,
+            Checks::all(),
+            &context,
+        )
+        .unwrap();
+    }
+    let mut output = Vec::new();
+    run_line_mut(
+        &mut output,
+        "jobber -E --csv pos,hours,rounded-hours",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+
+    // the sum of both jobs at the first day is rounded up to a quarter hour
+    assert_eq!(
+        clean(&output),
+        r#""Position","Hours","Rounded.Hours"
+1,0.08,0.25
+2,0.08,0
+3,0.08,0.25
+"#
+        .to_string()
+    );
+    assert_eq!(jobs.all(&context).hours_overall(&context), 0.5);
+}
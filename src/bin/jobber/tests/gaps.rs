@@ -0,0 +1,141 @@
+//! Testing option `--gaps`.
+
+use crate::*;
+
+/// Find gaps between jobs within working hours.
+///
+/// - [x] check argument parsing
+/// - [x] check found gaps
+/// - [ ] check output
+///
+#[test]
+fn test_gaps() {
+    let context = Context::new_test("2023-2-3 18:00");
+
+    assert_eq!(
+        parse_line("jobber --gaps 2-5", None, &context).unwrap(),
+        Command::Gaps {
+            range: Range::PositionRange(1, 4),
+            tags: None,
+        }
+    );
+
+    // configure working hours and minimum gap
+    let mut jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber --work-hours 8:00-17:00 --min-gap 30m",
+        None,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+
+    for line in [
+        // day one: 30 minutes gap at 10:00 and 15 minutes gap at 12:00
+        "jobber -s 2/1/2023,7:00 -e 10:00 -m a -t x",
+        "jobber -s 2/1/2023,10:30 -e 12:00 -m b -t x",
+        "jobber -s 2/1/2023,12:15 -e 14:00 -m c -t y",
+        // evening job leaves a gap until the end of working hours
+        "jobber -s 2/1/2023,20:00 -e 21:00 -m d -t y",
+        // day two: one hour gap which is partially before working hours
+        "jobber -s 2/2/2023,6:00 -e 7:00 -m e -t x",
+        "jobber -s 2/2/2023,9:00 -e 12:00 -m f -t x",
+        // day three: gaps from begin of working hours and until their end
+        "jobber -s 2/3/2023,10:00 -e 11:00 -m g -t y",
+    ] {
+        run_line_mut(
+            &mut std::io::stdout(),
+            line,
+            &mut jobs,
+            Checks::all_but(Check::UnknownTags),
+            &context,
+        )
+        .unwrap();
+    }
+
+    let gap = |start: &str, end: &str, before: Option<usize>, after: Option<usize>| Gap {
+        start: start.into(),
+        end: end.into(),
+        before,
+        after,
+    };
+
+    let operation = run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --gaps",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    if let Operation::Gaps(gaps, _, _) = operation {
+        assert_eq!(
+            gaps,
+            [
+                gap("2023-2-1 10:00", "2023-2-1 10:30", Some(0), Some(1)),
+                gap("2023-2-1 14:00", "2023-2-1 17:00", Some(2), Some(3)),
+                gap("2023-2-2 8:00", "2023-2-2 9:00", Some(4), Some(5)),
+                gap("2023-2-2 12:00", "2023-2-2 17:00", Some(5), None),
+                gap("2023-2-3 8:00", "2023-2-3 10:00", None, Some(6)),
+                gap("2023-2-3 11:00", "2023-2-3 17:00", Some(6), None),
+            ]
+        );
+    } else {
+        panic!("unexpected operation");
+    }
+
+    // filtering by tags selects the days but gaps are found between all jobs
+    let operation = run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --gaps 1-4 -t x",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    if let Operation::Gaps(gaps, _, _) = operation {
+        assert_eq!(
+            gaps,
+            [
+                gap("2023-2-1 10:00", "2023-2-1 10:30", Some(0), Some(1)),
+                gap("2023-2-1 14:00", "2023-2-1 17:00", Some(2), Some(3)),
+            ]
+        );
+    } else {
+        panic!("unexpected operation");
+    }
+
+    // jobs after midnight belong to the day before when a day boundary is set
+    for line in [
+        "jobber --day-boundary 4:00",
+        "jobber -s 2/3/2023,2:00 -e 3:00 -m h -t y",
+    ] {
+        run_line_mut(
+            &mut std::io::stdout(),
+            line,
+            &mut jobs,
+            Checks::all(),
+            &context,
+        )
+        .unwrap();
+    }
+    let operation = run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --gaps 2/2",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    if let Operation::Gaps(gaps, _, _) = operation {
+        assert_eq!(
+            gaps,
+            [
+                gap("2023-2-2 8:00", "2023-2-2 9:00", Some(4), Some(5)),
+                gap("2023-2-2 12:00", "2023-2-2 17:00", Some(5), Some(7)),
+            ]
+        );
+    } else {
+        panic!("unexpected operation");
+    }
+}
@@ -0,0 +1,188 @@
+//! Testing options `--invoice`, `--uninvoice` and `--unlock`.
+
+use crate::*;
+
+/// Mark jobs as invoiced and refuse changes within the invoiced period.
+///
+/// - [x] check argument parsing
+/// - [x] check database modification
+/// - [x] check locking
+/// - [x] check output
+///
+#[test]
+fn test_invoice() {
+    let context = Context::new_test("2023-2-3 12:00");
+
+    assert_eq!(
+        parse_line("jobber --invoice 1-2 --reference 2023-001", None, &context).unwrap(),
+        Command::Invoice {
+            range: Range::PositionRange(0, 1),
+            tags: None,
+            reference: Some("2023-001".into()),
+        }
+    );
+    assert_eq!(
+        parse_line("jobber --uninvoice 2 --unlock", None, &context).unwrap(),
+        Command::Invoice {
+            range: Range::At(vec![1]),
+            tags: None,
+            reference: None,
+        }
+    );
+    // removing an invoice needs to unlock
+    assert!(Args::try_parse_from(["jobber", "--uninvoice", "2"]).is_err());
+
+    let mut jobs = Jobs::new();
+    for line in [
+        "jobber -s 2/1/2023,8:00 -e 10:00 -m work",
+        "jobber -s 2/1/2023,12:00 -e 14:00 -m more",
+        "jobber -s 2/2/2023,8:00 -e 9:00 -m tomorrow",
+        "jobber --invoice 1-2 --reference 2023-001",
+    ] {
+        run_line_mut(
+            &mut std::io::stdout(),
+            line,
+            &mut jobs,
+            Checks::all(),
+            &context,
+        )
+        .unwrap();
+    }
+    assert_eq!(jobs[0].invoice, Some("2023-001".into()));
+    assert_eq!(jobs[1].invoice, Some("2023-001".into()));
+    assert_eq!(jobs[2].invoice, None);
+
+    // invoiced jobs and their times are locked
+    for line in [
+        "jobber --edit 1 -m changed",
+        "jobber --edit 3 -s 2/1/2023,13:00",
+        "jobber --delete 2",
+        "jobber --merge 2-3 -m merged",
+        "jobber -s 2/1/2023,9:30 -e 11:00 -m forgotten",
+        "jobber --invoice 2-3 --reference 2023-002",
+        "jobber --uninvoice 2 --unlock",
+    ] {
+        assert!(matches!(
+            run_line_mut(&mut std::io::stdout(), line, &mut jobs, Checks::all(), &context),
+            Err(Error::Locked(reference)) if reference == "2023-001"
+        ));
+    }
+    // confirming warnings does not unlock
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --delete 1",
+            &mut jobs,
+            Checks::all().omit_warnings(),
+            &context
+        ),
+        Err(Error::Locked(_))
+    ));
+    // jobs outside the period can still be changed
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --edit 3 -m changed",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+
+    // report shows invoiced hours
+    let list = jobs.all(&context);
+    assert_eq!(list.hours_invoiced(true, &context), 4.0);
+    assert_eq!(list.hours_invoiced(false, &context), 1.0);
+    assert!(list.summary(&context).contains("invoiced"));
+    let mut output = Vec::new();
+    run_line_mut(
+        &mut output,
+        "jobber -E --csv message,invoice",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "\"Message\",\"Invoice\"\n\"work\",\"2023-001\"\n\"more\",\"2023-001\"\n\"changed\",\n"
+    );
+
+    // time between invoiced jobs is not locked
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber -s 2/1/2023,10:30 -e 11:00 -m forgotten",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs.count(), 4);
+
+    // open jobs can not be invoiced
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber -s 2/3/2023,8:00 -m open",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --invoice 5 --reference 2023-002",
+            &mut jobs,
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::InvoiceOpenJob(4))
+    ));
+
+    // unlock to change invoiced jobs anyway (like --unlock does)
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --delete 2",
+        &mut jobs,
+        Checks::no_confirm().without(Check::Locked),
+        &context,
+    )
+    .unwrap();
+    // restoring an invoiced job is locked too
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --restore 2",
+            &mut jobs,
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::Locked(_))
+    ));
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --restore 2",
+        &mut jobs,
+        Checks::all().without(Check::Locked),
+        &context,
+    )
+    .unwrap();
+
+    // remove invoice to unlock permanently
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --uninvoice 1-2 --unlock",
+        &mut jobs,
+        Checks::all().without(Check::Locked),
+        &context,
+    )
+    .unwrap();
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --edit 1 -m changed",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs[0].message, Some("changed".into()));
+}
@@ -0,0 +1,108 @@
+//! Testing check `max-hours`.
+
+use crate::*;
+
+/// Warn if the work time of a day would exceed the maximum hours.
+///
+/// - [x] check adding, ending and editing jobs
+/// - [x] check open jobs until now
+/// - [x] check jobs crossing midnight
+/// - [x] check tag specific maximum
+/// - [x] check hours like in the report
+///
+#[test]
+fn test_max_hours() {
+    let context = Context::new_test("2023-2-4 12:00");
+
+    let mut jobs = Jobs::new();
+    let mut run = |line: &str, checks: Checks| {
+        run_line_mut(&mut std::io::sink(), line, &mut jobs, checks, &context)
+    };
+    run("jobber --max-hours 8", Checks::all()).unwrap();
+    run("jobber -s 2/1/2023,8:00 -e 14:00 -m work", Checks::all()).unwrap();
+
+    // adding a job
+    let result = run("jobber -s 2/1/2023,14:00 -e 17:00 -m work", Checks::all());
+    assert!(matches!(
+        &result,
+        Err(Error::Warnings(warnings)) if matches!(
+            &warnings[..],
+            [Warning::MaxHours { tag: None, max_hours: 8, .. }]
+        )
+    ));
+    if let Err(Error::Warnings(warnings)) = result {
+        assert_eq!(
+            warnings[0].to_string(),
+            "The work time at 2023-02-01 would sum up to 9.00 hours which exceeds the maximum of 8 hours."
+        );
+    }
+    run("jobber -s 2/1/2023,14:00 -e 16:00 -m work", Checks::all()).unwrap();
+
+    // editing a job
+    assert!(run("jobber --edit 2 -e 2/1/2023,17:00", Checks::all()).is_err());
+    run("jobber --edit 2 -e 2/1/2023,15:00 -m more", Checks::all()).unwrap();
+
+    // open jobs count until now
+    assert!(matches!(
+        run("jobber -s 2/2/2023,8:00 -m work", Checks::all()),
+        Err(Error::Warnings(warnings)) if warnings[0].to_string().contains("2023-02-02")
+    ));
+    run(
+        "jobber -s 2/2/2023,8:00 -m work",
+        Checks::all_but(Check::MaxHours),
+    )
+    .unwrap();
+
+    // ending an open job
+    assert!(matches!(
+        run("jobber -e 2/2/2023,17:00", Checks::all()),
+        Err(Error::Warnings(warnings)) if warnings[0].check() == Check::MaxHours
+    ));
+    run("jobber -e 2/2/2023,12:00", Checks::all()).unwrap();
+
+    // jobs crossing midnight count into both days
+    run(
+        "jobber -s 2/2/2023,20:00 -e 2/3/2023,6:00 -m night",
+        Checks::all(),
+    )
+    .unwrap();
+    assert!(matches!(
+        run("jobber -s 2/3/2023,7:00 -e 10:00 -m work", Checks::all()),
+        Err(Error::Warnings(warnings)) if warnings[0].to_string().contains("2023-02-03")
+    ));
+    run(
+        "jobber -s 2/3/2023,7:00 -e 10:00 -m work",
+        Checks::all_but(Check::MaxHours),
+    )
+    .unwrap();
+
+    // maximum of a specific tag
+    run("jobber --max-hours 2 -t client", Checks::all()).unwrap();
+    assert!(matches!(
+        run("jobber -s 2/4/2023,8:00 -e 11:00 -m work -t client", Checks::all_but(Check::UnknownTags)),
+        Err(Error::Warnings(warnings)) if matches!(
+            &warnings[..],
+            [Warning::MaxHours { tag: Some(tag), max_hours: 2, .. }] if tag == "client"
+        )
+    ));
+    run(
+        "jobber -s 2/4/2023,8:00 -e 10:00 -m work -t client",
+        Checks::all_but(Check::UnknownTags),
+    )
+    .unwrap();
+    run("jobber -s 2/4/2023,10:00 -e 11:00 -m work", Checks::all()).unwrap();
+
+    // hours are summed up like in the report (including minimum billable hours)
+    run(
+        "jobber --max-hours 1 --min-hours 2 -t support",
+        Checks::all(),
+    )
+    .unwrap();
+    assert!(matches!(
+        run("jobber -s 1/31/2023,8:00 -e 8:30 -m call -t support", Checks::all_but(Check::UnknownTags)),
+        Err(Error::Warnings(warnings)) if matches!(
+            &warnings[..],
+            [Warning::MaxHours { hours, max_hours: 1, .. }] if *hours == 2.0
+        )
+    ));
+}
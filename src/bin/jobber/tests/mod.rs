@@ -1,16 +1,29 @@
 mod add;
 mod back;
+mod billable;
+mod checks;
+mod complete;
+mod configuration;
 mod delete;
 mod edit;
+mod editor;
 mod export;
+mod gaps;
+mod invoice;
+mod max_hours;
+mod non_interactive;
+mod pause;
+mod porcelain;
+mod profile;
 mod range;
+mod relative;
+mod remind;
+mod split;
 mod start;
-
-use regex::Regex;
+mod status;
+mod switch;
 
 // remove all ANSI color codes from output
-fn clean(output: &Vec<u8>) -> String {
-    let re = Regex::new(r"\u{1b}\[([0-9]{1,2}(;[0-9]{1,2})*)?[m|K]").unwrap();
-    re.replace_all(std::str::from_utf8(output.as_slice()).unwrap(), "")
-        .to_string()
+fn clean(output: &[u8]) -> String {
+    crate::plain(output)
 }
@@ -0,0 +1,78 @@
+//! Testing options `--non-interactive` and `--policy`.
+
+use crate::*;
+
+/// Handle warnings by policies and fail instead of asking the user.
+///
+/// - [x] check argument parsing
+/// - [x] check policies
+/// - [x] check database modification
+/// - [x] check exit codes
+///
+#[test]
+fn test_non_interactive() {
+    let context = Context::new_test("2023-2-1 12:00");
+
+    let args = Args::parse_from([
+        "jobber",
+        "--non-interactive",
+        "--policy",
+        "warn,overlaps=fail",
+    ]);
+    assert!(args.non_interactive);
+    let policies = Policies::parse(&args.policy.unwrap()).unwrap();
+    assert_eq!(policies.get(Check::Overlaps), Policy::Fail);
+    assert_eq!(policies.get(Check::UnknownTags), Policy::Warn);
+    assert_eq!(Policies::default().get(Check::UnknownTags), Policy::Fail);
+    assert!(matches!(
+        Policies::parse("overlaps=maybe"),
+        Err(Error::PolicyFormat(policy)) if policy == "maybe"
+    ));
+    assert!(matches!(
+        Policies::parse("overlapping=accept"),
+        Err(Error::UnknownCheck(check)) if check == "overlapping"
+    ));
+
+    let mut jobs = Jobs::new();
+    let mut process_line = |line: &str, policy: &str| {
+        let mut command = parse_line(line, None, &context).unwrap();
+        process(
+            &mut std::io::sink(),
+            &mut jobs,
+            &mut command,
+            Checks::all(),
+            Some(&Policies::parse(policy).unwrap()),
+            &context,
+        )
+    };
+
+    // unknown tags fail by default
+    let result = process_line("jobber -s 2/1/2023,8:00 -e 10:00 -m work -t client", "fail");
+    assert!(matches!(&result, Err(Error::Warnings(warnings)) if warnings.len() == 1));
+    assert_eq!(result.unwrap_err().exit_code(), 3);
+    process_line(
+        "jobber -s 2/1/2023,8:00 -e 10:00 -m work -t client",
+        "unknown-tags=accept",
+    )
+    .unwrap();
+
+    // overlapping fails even if other warnings are accepted
+    assert!(matches!(
+        process_line(
+            "jobber -s 2/1/2023,9:00 -e 11:00 -m work -t other",
+            "accept,overlaps=fail"
+        ),
+        Err(Error::Warnings(warnings)) if matches!(warnings[..], [Warning::Overlaps { .. }])
+    ));
+    process_line("jobber -s 2/1/2023,9:00 -e 11:00 -m work -t other", "warn").unwrap();
+
+    // missing message fails instead of asking
+    let result = process_line("jobber -s 2/1/2023,11:00 -e 12:00", "accept");
+    assert!(matches!(&result, Err(Error::EnterMessage)));
+    assert_eq!(result.unwrap_err().exit_code(), 4);
+
+    // deletion needs to be accepted
+    assert!(process_line("jobber --delete 2", "fail").is_err());
+    process_line("jobber --delete 2", "confirm-deletion=accept").unwrap();
+    assert!(jobs[1].is_deleted());
+}
@@ -0,0 +1,182 @@
+//! Testing options `--pause` and `--resume`.
+
+use crate::*;
+
+/// Start a job, pause and resume it and then end it.
+///
+/// - [x] check argument parsing
+/// - [x] check database modification
+/// - [ ] check output
+///
+#[test]
+fn test_pause() {
+    let context = Context::new_test("2023-2-1 18:00");
+
+    assert_eq!(
+        parse_line("jobber --pause 12:00", None, &context).unwrap(),
+        Command::Pause {
+            time: "2023-2-1 12:00".into()
+        }
+    );
+    assert_eq!(
+        parse_line("jobber --resume", None, &context).unwrap(),
+        Command::Resume {
+            time: "2023-2-1 18:00".into()
+        }
+    );
+
+    // start a job
+    let jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber -s 8:00 -m job",
+        None,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+
+    // can not resume without pause
+    let mut jobs = jobs;
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --resume 13:00",
+            &mut jobs,
+            Checks::all(),
+            &context
+        ),
+        Err(Error::NotPaused)
+    ));
+
+    // take a break for lunch
+    let jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber --pause 12:00",
+        Some(jobs),
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert!(jobs[0].is_paused());
+
+    // can not pause twice
+    let mut jobs = jobs;
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --pause 12:30",
+            &mut jobs,
+            Checks::all(),
+            &context
+        ),
+        Err(Error::AlreadyPaused(_))
+    ));
+
+    // back to work
+    let jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber --resume 12:30",
+        Some(jobs),
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert!(!jobs[0].is_paused());
+
+    // end job
+    let jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber -e 17:00",
+        Some(jobs),
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs.count(), 1);
+    assert_eq!(jobs[0].breaks.len(), 1);
+    assert_eq!(jobs[0].hours(&Properties::default(), &context), 8.5);
+
+    // a job added in the break does not overlap
+    let jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber -s 12:00 -e 12:30 -m lunch-meeting",
+        Some(jobs),
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs.count(), 2);
+}
+
+/// Breaks must lie within their job.
+///
+/// - [x] check ending before a break
+/// - [x] check editing start or end beyond a break
+///
+#[test]
+fn test_pause_outside() {
+    let context = Context::new_test("2023-2-1 18:00");
+
+    let mut jobs = Jobs::new();
+    for line in ["jobber -s 8:00 -m job", "jobber --pause 11:00"] {
+        run_line_mut(
+            &mut std::io::sink(),
+            line,
+            &mut jobs,
+            Checks::all(),
+            &context,
+        )
+        .unwrap();
+    }
+
+    // ending before the open break is rejected
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::sink(),
+            "jobber -e 10:30",
+            &mut jobs,
+            Checks::all(),
+            &context
+        ),
+        Err(Error::EndBeforeStart(_, _))
+    ));
+    run_line_mut(
+        &mut std::io::sink(),
+        "jobber --resume 12:00",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    run_line_mut(
+        &mut std::io::sink(),
+        "jobber -e 17:00",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+
+    // moving start or end beyond the break is rejected
+    for line in ["jobber --edit 1 -s 11:30", "jobber --edit 1 -e 11:30"] {
+        assert!(matches!(
+            run_line_mut(
+                &mut std::io::sink(),
+                line,
+                &mut jobs,
+                Checks::all(),
+                &context
+            ),
+            Err(Error::BreakOutsideJob(_))
+        ));
+    }
+    run_line_mut(
+        &mut std::io::sink(),
+        "jobber --edit 1 -e 16:00",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs[0].hours(&Properties::default(), &context), 7.0);
+}
@@ -0,0 +1,122 @@
+//! Testing options `--quiet` and `--porcelain`.
+
+use crate::*;
+
+/// Output results of operations as JSON.
+///
+/// - [x] check argument parsing
+/// - [x] check JSON of operations
+/// - [x] check output
+/// - [x] check embedded output of listing
+/// - [x] check that porcelain never asks
+///
+#[test]
+fn test_porcelain() {
+    let context = Context::new_test("2023-2-1 12:00");
+
+    // JSON of operations
+    let mut jobs = Jobs::new();
+    let operation = run_line_mut(
+        &mut std::io::sink(),
+        "jobber -s 2/1/2023,8:00 -e 9:00 -m work",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    let json = operation.to_json(&jobs, &context);
+    assert_eq!(json["operation"], "push");
+    assert_eq!(json["jobs"][0]["pos"], 1);
+    assert_eq!(json["jobs"][0]["job"]["message"], "work");
+
+    let operation = run_line_mut(
+        &mut std::io::sink(),
+        "jobber --edit 1 -m changed",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    let json = operation.to_json(&jobs, &context);
+    assert_eq!(json["operation"], "modify");
+    assert_eq!(json["jobs"][0]["job"]["message"], "changed");
+
+    let operation = run_line_mut(
+        &mut std::io::sink(),
+        "jobber --delete 1",
+        &mut jobs,
+        Checks::all_but(Check::ConfirmDeletion),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(
+        operation.to_json(&jobs, &context).to_string(),
+        r#"{"operation":"delete","positions":[1]}"#
+    );
+
+    // output of a whole run
+    let filename = std::env::temp_dir().join("jobber_test_porcelain.json");
+    let mut output = Vec::new();
+    run(
+        &mut output,
+        Args::parse_from([
+            "jobber",
+            "-f",
+            &filename.to_string_lossy(),
+            "--dry",
+            "--porcelain",
+            "-s",
+            "2/1/2023,8:00",
+            "-m",
+            "work",
+        ]),
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["operation"], "push");
+    assert_eq!(json["jobs"][0]["job"]["start"], "2023-02-01T08:00:00Z");
+    assert!(!filename.exists());
+
+    // output of listing is embedded into JSON
+    run_line_mut(
+        &mut std::io::sink(),
+        "jobber -s 2/1/2023,10:00 -e 11:00 -m listed",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    let file = tempfile::NamedTempFile::new().unwrap();
+    let filename = file.path().to_string_lossy().to_string();
+    jobs.save(&filename).unwrap();
+    let porcelain = |args: &[&str], output: &mut Vec<u8>| {
+        run(
+            output,
+            Args::parse_from(
+                ["jobber", "-f", &filename, "--dry", "--porcelain"]
+                    .iter()
+                    .chain(args),
+            ),
+            Checks::all(),
+            &context,
+        )
+    };
+    let mut output = Vec::new();
+    porcelain(&["-l"], &mut output).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["operation"], "list");
+    assert!(json["output"].as_str().unwrap().contains("listed"));
+    assert!(!json["output"].as_str().unwrap().contains('\u{1b}'));
+
+    // porcelain is non-interactive and so fails on warnings
+    assert!(matches!(
+        porcelain(&["--delete", "2"], &mut Vec::new()),
+        Err(Error::Warnings(_))
+    ));
+    assert!(Args::try_parse_from(["jobber", "--porcelain", "--policy", "accept"]).is_ok());
+    assert!(Args::try_parse_from(["jobber", "--policy", "accept"]).is_err());
+
+    assert!(Args::parse_from(["jobber", "-q", "-l"]).quiet);
+}
@@ -0,0 +1,140 @@
+//! Testing options `--profile`, `--use-profile` and `--profiles`.
+
+use super::clean;
+use crate::*;
+
+/// Select databases by profiles and report jobs of several profiles.
+///
+/// - [x] check argument parsing
+/// - [x] check profile selection
+/// - [x] check output
+/// - [x] check error if not reporting
+/// - [x] check missing databases and settings of each profile
+///
+#[test]
+fn test_profile() {
+    let context = Context::new_test("2023-2-1 12:00");
+
+    assert!(Args::try_parse_from(["jobber", "--profiles", "all"]).is_err());
+    assert!(Args::try_parse_from(["jobber", "--profile", "work", "-f", "jobber.json"]).is_err());
+    assert!(Args::try_parse_from(["jobber", "--profiles", "all", "-r"]).is_ok());
+
+    assert!(matches!(
+        run(
+            &mut std::io::sink(),
+            Args::parse_from(["jobber", "--profiles", "all", "-r", "--pause"]),
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::ProfilesWithoutReport)
+    ));
+
+    // select profiles
+    let files = [(); 3].map(|_| tempfile::NamedTempFile::new().unwrap());
+    let [default, work, private] = files
+        .each_ref()
+        .map(|file| file.path().to_string_lossy().to_string());
+    let mut cfg = Config {
+        database: default.clone(),
+        profile: None,
+        profiles: [
+            ("work".into(), work.clone()),
+            ("private".into(), private.clone()),
+        ]
+        .into(),
+    };
+    assert_eq!(cfg.database(None).unwrap(), default);
+    assert_eq!(cfg.database(Some("work")).unwrap(), work);
+    assert!(matches!(
+        cfg.database(Some("hobby")),
+        Err(Error::UnknownProfile(profile)) if profile == "hobby"
+    ));
+    assert!(cfg.use_profile("hobby").is_err());
+    assert_eq!(cfg.use_profile("private").unwrap(), private);
+    assert_eq!(cfg.database(None).unwrap(), private);
+    assert_eq!(cfg.database(Some("default")).unwrap(), default);
+    assert_eq!(cfg.use_profile("default").unwrap(), default);
+    assert_eq!(cfg.profile, None);
+    assert_eq!(
+        cfg.profiles("all").unwrap(),
+        [
+            ("default".to_string(), default.clone()),
+            ("private".to_string(), private.clone()),
+            ("work".to_string(), work.clone())
+        ]
+    );
+    assert_eq!(
+        cfg.profiles("work,default").unwrap(),
+        [
+            ("work".to_string(), work.clone()),
+            ("default".to_string(), default.clone())
+        ]
+    );
+
+    // report jobs of several databases
+    for (filename, end) in [(&default, "8:30"), (&work, "10:00"), (&private, "9:00")] {
+        std::fs::remove_file(filename).unwrap();
+        run(
+            &mut std::io::sink(),
+            Args::parse_from([
+                "jobber",
+                "-q",
+                "-f",
+                filename,
+                "-s",
+                "2/1/2023,8:00",
+                "-e",
+                end,
+                "-m",
+                "work",
+            ]),
+            Checks::all(),
+            &context,
+        )
+        .unwrap();
+    }
+    let mut output = Vec::new();
+    profile::report(
+        &mut output,
+        &cfg.profiles("all").unwrap(),
+        || {
+            Ok(Command::Report {
+                range: Range::All,
+                tags: None,
+            })
+        },
+        &context,
+    )
+    .unwrap();
+    let output = clean(&output);
+    assert!(output.contains(&format!("Profile 'work' ({work}):")));
+    assert!(output.contains("private: 1 job(s), 1 hours"));
+    assert!(output.contains("work: 1 job(s), 2 hours"));
+    assert!(output.contains("default: 1 job(s), 0.5 hours"));
+    assert!(output.ends_with("All profiles: 3 job(s), 3.5 hours\n"));
+
+    // missing databases are skipped and every profile is reported with it's own settings
+    std::fs::remove_file(&default).unwrap();
+    run(
+        &mut std::io::sink(),
+        Args::parse_from(["jobber", "-q", "-f", &private, "--date-order", "dmy"]),
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    let mut output = Vec::new();
+    profile::report(
+        &mut output,
+        &cfg.profiles("all").unwrap(),
+        || parse_line("jobber -r 1/2/2023", None, &context),
+        &context,
+    )
+    .unwrap();
+    let output = clean(&output);
+    assert!(output.contains(&format!(
+        "Profile 'default' ({default}): database not found"
+    )));
+    assert!(output.contains("default: database not found"));
+    assert!(output.contains("private: 1 job(s), 1 hours"));
+    assert!(output.ends_with("All profiles: 1 job(s), 1 hours\n"));
+}
@@ -0,0 +1,98 @@
+//! Testing relative dates and times with options `-s`, `-b` and `-e`.
+
+use crate::*;
+
+/// Start, add and end jobs by using relative dates and times.
+///
+/// - [x] check argument parsing
+/// - [ ] check database modification
+/// - [ ] check output
+///
+#[test]
+fn test_relative() {
+    let context = Context::new_test("2023-02-01 12:00");
+
+    // start a job some minutes ago
+    assert_eq!(
+        parse_line("jobber -s -20m", None, &context).unwrap(),
+        Command::Start {
+            start: "2023-02-01 11:40".into(),
+            message: None,
+            tags: None,
+            billable: None,
+        }
+    );
+
+    assert_eq!(
+        parse_line("jobber -s=-20m", None, &context).unwrap(),
+        parse_line("jobber --start -20m", None, &context).unwrap()
+    );
+    // options following an option without a value are still options
+    assert!(matches!(
+        parse_line("jobber -s -e 13:00 -m work", None, &context).unwrap(),
+        Command::Add { message: Some(Some(message)), .. } if message == "work"
+    ));
+    assert_eq!(
+        args::join_offsets(["jobber", "-s", "-20m", "-m", "-1"]),
+        ["jobber", "-s=-20m", "-m", "-1"]
+    );
+
+    // end an open job some time ago
+    assert_eq!(
+        parse_line(
+            "jobber --end -1h15m",
+            Some("2023-02-01 8:00".into()),
+            &context
+        )
+        .unwrap(),
+        Command::End {
+            end: "2023-02-01 10:45".into(),
+            message: None,
+            tags: None
+        }
+    );
+
+    // add a job yesterday
+    assert_eq!(
+        parse_args(
+            &["jobber", "-s", "yesterday 9:00", "-e", "17:00"],
+            None,
+            &context
+        )
+        .unwrap(),
+        Command::Add {
+            start: "2023-01-31 9:00".into(),
+            end: "2023-01-31 17:00".into(),
+            message: None,
+            tags: None,
+            billable: None,
+        }
+    );
+
+    // end an open job at last friday's evening
+    assert_eq!(
+        parse_args(
+            &["jobber", "-e", "fri,18:00"],
+            Some("2023-01-27 8:00".into()),
+            &context
+        )
+        .unwrap(),
+        Command::End {
+            end: "2023-01-27 18:00".into(),
+            message: None,
+            tags: None
+        }
+    );
+
+    // continue work half an hour ago
+    assert_eq!(
+        parse_line("jobber -b now-30m", None, &context).unwrap(),
+        Command::Back {
+            start: "2023-02-01 11:30".into(),
+            message: None,
+            tags: None,
+            template: Template::Last,
+            billable: None,
+        }
+    );
+}
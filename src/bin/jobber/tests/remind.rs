@@ -0,0 +1,106 @@
+//! Testing option `--remind`.
+
+use crate::*;
+
+/// Detect an open job which lasts too long or crosses midnight.
+///
+/// - [x] check argument parsing
+/// - [x] check reminders
+/// - [x] check ending the open job
+///
+#[test]
+fn test_remind() {
+    let context = Context::new_test("2023-2-1 18:00");
+
+    assert_eq!(
+        parse_line("jobber --remind", None, &context).unwrap(),
+        Command::Remind
+    );
+
+    // no open job
+    let mut jobs = Jobs::new();
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --remind",
+            &mut jobs,
+            Checks::all(),
+            &context
+        ),
+        Ok(Operation::Remind(None))
+    ));
+
+    // open job within maximum hours
+    for line in ["jobber --max-hours 8", "jobber -s 2/1/2023,12:00 -m work"] {
+        run_line_mut(
+            &mut std::io::stdout(),
+            line,
+            &mut jobs,
+            Checks::all(),
+            &context,
+        )
+        .unwrap();
+    }
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --remind",
+            &mut jobs,
+            Checks::all(),
+            &context
+        ),
+        Ok(Operation::Remind(None))
+    ));
+
+    // open job exceeds maximum hours
+    let context = Context::new_test("2023-2-1 21:00");
+    let operation = run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --remind",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    let reminder = if let Operation::Remind(Some(reminder)) = operation {
+        reminder
+    } else {
+        panic!("missing reminder");
+    };
+    assert_eq!(reminder, Reminder::Overtime(0, 8, "2023-2-1 20:00".into()));
+
+    // open job crosses midnight (end at working hours if configured)
+    let context = Context::new_test("2023-2-2 8:00");
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --max-hours 24 --work-hours 8:00-17:00",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert!(matches!(
+        run_line_mut(&mut std::io::stdout(), "jobber --remind", &mut jobs, Checks::all(), &context),
+        Ok(Operation::Remind(Some(Reminder::Overnight(0, end)))) if end == "2023-2-1 17:00".into()
+    ));
+
+    // end job at the last plausible time
+    jobs.process(
+        &mut std::io::stdout(),
+        &reminder.command(),
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs[0].end, Some("2023-2-1 20:00".into()));
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --remind",
+            &mut jobs,
+            Checks::all(),
+            &context
+        ),
+        Ok(Operation::Remind(None))
+    ));
+}
@@ -0,0 +1,175 @@
+//! Testing options `--split` and `--merge`.
+
+use crate::*;
+
+/// Split a job into two and merge them again.
+///
+/// - [x] check argument parsing
+/// - [x] check database modification
+/// - [ ] check output
+///
+#[test]
+fn test_split_and_merge() {
+    let context = Context::new_test("2023-2-1 18:00");
+
+    assert_eq!(
+        parse_line("jobber --split 1 --at 12:00 -m second -t b", None, &context).unwrap(),
+        Command::Split {
+            pos: 0,
+            at: "2023-2-1 12:00".into(),
+            message: Some(Some("second".into())),
+            tags: Some(TagSet::from("b")),
+        }
+    );
+
+    // add a job
+    let mut jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber -s 8:00 -e 16:00 -m first -t a",
+        None,
+        Checks::omit(),
+        &context,
+    )
+    .unwrap();
+
+    // split time must be within the job
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --split 1 --at 17:00",
+            &mut jobs,
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::EndBeforeStart(_, _))
+    ));
+
+    // split the job
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --split 1 --at 12:00 -m second -t b",
+        &mut jobs,
+        Checks::all_but(Check::UnknownTags),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs.count(), 2);
+    assert_eq!(jobs[0].end, Some("2023-2-1 12:00".into()));
+    assert_eq!(jobs[0].message, Some("first".into()));
+    assert_eq!(jobs[1].start, "2023-2-1 12:00".into());
+    assert_eq!(jobs[1].end, Some("2023-2-1 16:00".into()));
+    assert_eq!(jobs[1].message, Some("second".into()));
+    assert_eq!(jobs[1].tags, TagSet::from("b"));
+
+    // add another job after a gap
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber -s 16:30 -e 17:00 -m second -t b",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+
+    // can not merge a single job
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --merge 1",
+            &mut jobs,
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::MergeTooFew(1))
+    ));
+
+    // merge second and third job
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --merge 2-3",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs.count(), 2);
+    assert!(jobs[2].is_deleted());
+    assert_eq!(jobs[1].end, Some("2023-2-1 17:00".into()));
+    assert_eq!(jobs[1].message, Some("second".into()));
+    assert_eq!(jobs[1].breaks.len(), 1);
+    assert_eq!(jobs[1].hours(&Properties::default(), &context), 4.5);
+
+    // split needs a message if one is requested
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --split 1 --at 10:00 -m",
+            &mut jobs,
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::EnterMessage)
+    ));
+
+    // both parts of an open job without message get the new one
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber -s 17:00",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --split 4 --at 17:30 -m third",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs[3].message, Some("third".into()));
+    assert_eq!(jobs[4].message, Some("third".into()));
+    assert!(jobs[4].is_open());
+
+    // merging different messages needs a new one
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --merge 1-2",
+            &mut jobs,
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::EnterMessage)
+    ));
+    assert_eq!(jobs[0].end, Some("2023-2-1 12:00".into()));
+    assert_eq!(
+        jobs.message_to_edit(
+            &parse_line("jobber --merge 1-2", None, &context).unwrap(),
+            &context
+        ),
+        Some("first\nsecond".into())
+    );
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --merge 1-2 -m both",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs[0].end, Some("2023-2-1 17:00".into()));
+    assert_eq!(jobs[0].message, Some("both".into()));
+    // can not merge jobs with another one between them
+    assert!(matches!(
+        run_line_mut(
+            &mut std::io::stdout(),
+            "jobber --merge 1,5",
+            &mut jobs,
+            Checks::all(),
+            &context,
+        ),
+        Err(Error::NotAdjacent(_, between)) if between == "2023-2-1 17:00".into()
+    ));
+}
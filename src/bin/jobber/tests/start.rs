@@ -18,7 +18,8 @@ fn test_start() {
         Command::Start {
             start: "2023-01-01 12:00".into(),
             message: None,
-            tags: None
+            tags: None,
+            billable: None,
         }
     );
 
@@ -28,7 +29,8 @@ fn test_start() {
         Command::Start {
             start: "2023-02-01 13:00".into(),
             message: None,
-            tags: None
+            tags: None,
+            billable: None,
         }
     );
 
@@ -38,7 +40,44 @@ fn test_start() {
         Command::Start {
             start: "2023-01-01 13:00".into(),
             message: None,
-            tags: None
+            tags: None,
+            billable: None,
         }
     );
 }
+
+/// List a started job.
+///
+/// - [ ] check argument parsing
+/// - [ ] check database modification
+/// - [x] check output
+///
+#[test]
+fn test_start_list() {
+    let context = Context::new_test("2023-01-01 12:00");
+
+    let mut jobs = Jobs::new();
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber -s 8:00 -e 9:00 -m finished",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber -s 10:00 -m started",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+
+    // the open job counts until the time of the context
+    let mut output = Vec::new();
+    run_line_mut(&mut output, "jobber -l", &mut jobs, Checks::all(), &context).unwrap();
+    let output = super::clean(&output);
+    assert!(output.contains("  Hours: 2 ++\nMessage: started"));
+    assert!(output.contains("Total: 2 job(s), 3 hours"));
+}
@@ -0,0 +1,162 @@
+//! Testing option `--status`.
+
+use crate::*;
+
+/// Print status of the open job.
+///
+/// - [x] check argument parsing
+/// - [x] check output in default, custom and JSON format
+/// - [x] check reading the open job from a database file
+///
+#[test]
+fn test_status() {
+    let context = Context::new_test("2023-2-1 10:30");
+
+    assert_eq!(
+        parse_line("jobber --status", None, &context).unwrap(),
+        Command::Status {
+            format: STATUS_FORMAT.into()
+        }
+    );
+
+    // no open job (within a fixed time zone)
+    let mut jobs = Jobs::new();
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --time-zone Europe/Berlin",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    let context = Context::new_test("2023-2-1 10:30");
+    let mut output = Vec::new();
+    run_line_mut(
+        &mut output,
+        "jobber --status",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "");
+
+    let mut output = Vec::new();
+    run_line_mut(
+        &mut output,
+        "jobber --status json",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "null\n");
+
+    // open job with multi line message
+    run_args_mut(
+        &mut std::io::stdout(),
+        &[
+            "jobber",
+            "-s",
+            "2/1/2023,8:00",
+            "-m",
+            "work\nmore",
+            "-t",
+            "a,b",
+        ],
+        &mut jobs,
+        Checks::all_but(Check::UnknownTags),
+        &context,
+    )
+    .unwrap();
+
+    let mut output = Vec::new();
+    run_line_mut(
+        &mut output,
+        "jobber --status",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "a,b work 2:30\n");
+
+    let mut output = Vec::new();
+    run_args_mut(
+        &mut output,
+        &["jobber", "--status", "[{pos}] {start} {hours}"],
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "[1] 08:00 2.50\n");
+
+    // start time is formatted with the configured clock
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber --clock 12",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    let mut output = Vec::new();
+    run_args_mut(
+        &mut output,
+        &["jobber", "--status", "{start}"],
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "08:00 AM\n");
+
+    let mut output = Vec::new();
+    run_line_mut(
+        &mut output,
+        "jobber --status json",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        r#"{"message":"work","minutes":150,"paused":false,"pos":1,"start":"2023-02-01T08:00:00+01:00","tags":["a","b"]}"#
+            .to_string()
+            + "\n"
+    );
+
+    // a whole run only reads the open job from the database file
+    run_line_mut(
+        &mut std::io::stdout(),
+        "jobber -s 2/1/2023,6:00 -e 7:00 -m before",
+        &mut jobs,
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    let file = tempfile::NamedTempFile::new().unwrap();
+    let filename = file.path().to_string_lossy().to_string();
+    jobs.save(&filename).unwrap();
+    assert_eq!(
+        Jobs::load_open(&filename).unwrap(),
+        Some((0, jobs[0].clone()))
+    );
+    let mut output = Vec::new();
+    run(
+        &mut output,
+        Args::parse_from([
+            "jobber",
+            "-f",
+            &filename,
+            "--status",
+            "[{pos}] {start} {tags}",
+        ]),
+        Checks::all(),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "[1] 08:00 AM a,b\n");
+}
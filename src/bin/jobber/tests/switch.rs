@@ -0,0 +1,83 @@
+//! Testing option `--switch`.
+
+use crate::*;
+
+/// Start a job and switch to another one.
+///
+/// - [x] check argument parsing
+/// - [x] check database modification
+/// - [ ] check output
+///
+#[test]
+fn test_switch() {
+    let context = Context::new_test("2023-2-1 12:00");
+
+    assert_eq!(
+        parse_line("jobber --switch 10:00 -m other", None, &context).unwrap(),
+        Command::Switch {
+            time: "2023-2-1 10:00".into(),
+            message: Some(Some("other".into())),
+            tags: None,
+            end_message: None,
+            billable: None,
+        }
+    );
+
+    // can not switch without open job
+    assert!(matches!(
+        run_line(
+            &mut std::io::stdout(),
+            "jobber --switch",
+            None,
+            Checks::all(),
+            &context
+        ),
+        Err(Error::NoOpenJob)
+    ));
+
+    // start a job without message
+    let mut jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber -s 8:00 -t tag",
+        None,
+        Checks::all_but(Check::UnknownTags),
+        &context,
+    )
+    .unwrap();
+
+    // open job needs a message to be ended
+    let mut command = parse_line("jobber --switch 10:00 -m other", None, &context).unwrap();
+    assert!(matches!(
+        jobs.process(&mut std::io::stdout(), &command, Checks::all(), &context),
+        Err(Error::EnterMessage)
+    ));
+    assert_eq!(jobs.count(), 1);
+    assert!(jobs[0].is_open());
+
+    // enter message and switch
+    command.set_message("first".into());
+    jobs.process(&mut std::io::stdout(), &command, Checks::all(), &context)
+        .unwrap();
+    assert_eq!(jobs.count(), 2);
+    assert_eq!(jobs[0].end, Some("2023-2-1 10:00".into()));
+    assert_eq!(jobs[0].message, Some("first".into()));
+    assert_eq!(jobs[1].start, "2023-2-1 10:00".into());
+    assert!(jobs[1].is_open());
+    assert_eq!(jobs[1].message, Some("other".into()));
+    assert_eq!(jobs[1].tags, jobs[0].tags);
+
+    // switch again and copy message but change tags
+    let jobs = run_line(
+        &mut std::io::stdout(),
+        "jobber --switch 11:00 -t +new",
+        Some(jobs),
+        Checks::all_but(Check::UnknownTags),
+        &context,
+    )
+    .unwrap();
+    assert_eq!(jobs.count(), 3);
+    assert_eq!(jobs[1].end, Some("2023-2-1 11:00".into()));
+    assert_eq!(jobs[2].message, Some("other".into()));
+    assert!(jobs[2].tags.contains(&"tag".into()));
+    assert!(jobs[2].tags.contains(&"new".into()));
+}
@@ -0,0 +1,499 @@
+//! Full screen terminal user interface to browse and edit jobs.
+
+use chrono::{Datelike, Days, NaiveDate};
+use jobberdb::prelude::*;
+use std::io::Write;
+use termion::{
+    clear, color::*, cursor, event::Key, input::TermRead, raw::IntoRawMode,
+    screen::IntoAlternateScreen, style,
+};
+
+/// Format which is used to pre-fill date and time input.
+const INPUT_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// Help which is shown in the status line.
+const HELP: &str =
+    "←/→ day  w week  ↑/↓ select  s start  e end  m message  t tags  d delete  u restore  q quit";
+
+/// Period of time which is shown.
+#[derive(Clone, Copy, PartialEq)]
+enum Period {
+    Day,
+    Week,
+}
+
+/// State of the terminal user interface.
+struct Tui<'a> {
+    /// Database to browse and edit.
+    jobs: &'a mut Jobs,
+    /// Temporal context.
+    context: &'a Context,
+    /// Any date within the shown period.
+    date: NaiveDate,
+    /// Length of the shown period.
+    period: Period,
+    /// Checks to process changes with.
+    checks: Checks,
+    /// Index of the selected job within the shown jobs.
+    selected: usize,
+    /// First line of the job list which is shown.
+    offset: usize,
+    /// Message to show in the status line (or help if empty).
+    status: String,
+}
+
+/// Browse and edit jobs in full screen mode until user quits.
+pub fn run(jobs: &mut Jobs, checks: Checks, context: &Context) -> Result<(), Error> {
+    let mut screen = std::io::stdout().into_raw_mode()?.into_alternate_screen()?;
+    write!(screen, "{}", cursor::Hide)?;
+    let mut tui = Tui::new(jobs, checks, context);
+    let result = tui.run(&mut screen);
+    write!(screen, "{}", cursor::Show)?;
+    screen.flush()?;
+    result
+}
+
+impl<'a> Tui<'a> {
+    /// Create state which shows the current day.
+    fn new(jobs: &'a mut Jobs, checks: Checks, context: &'a Context) -> Self {
+        Self {
+            jobs,
+            context,
+            date: context.time().into_local().date(),
+            period: Period::Day,
+            checks,
+            selected: 0,
+            offset: 0,
+            status: String::new(),
+        }
+    }
+    /// Process user input until user quits.
+    fn run<W: Write>(&mut self, screen: &mut W) -> Result<(), Error> {
+        loop {
+            self.draw(screen)?;
+            let key = if let Some(key) = std::io::stdin().keys().next() {
+                key?
+            } else {
+                return Ok(());
+            };
+            self.status.clear();
+            match key {
+                Key::Char('q') | Key::Esc | Key::Ctrl('c') => return Ok(()),
+                Key::Up | Key::Char('k') => self.selected = self.selected.saturating_sub(1),
+                Key::Down | Key::Char('j') => self.selected += 1,
+                Key::Left | Key::Char('h') => self.step(false),
+                Key::Right | Key::Char('l') => self.step(true),
+                Key::Char('w') => {
+                    self.period = if self.period == Period::Day {
+                        Period::Week
+                    } else {
+                        Period::Day
+                    };
+                    self.selected = 0;
+                    self.offset = 0;
+                }
+                Key::Char(c) if "semtdu".contains(c) => {
+                    if let Err(err) = self.edit(screen, c) {
+                        self.status = err.to_string();
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+    /// Move to the next or previous period.
+    fn step(&mut self, forward: bool) {
+        let days = Days::new(match self.period {
+            Period::Day => 1,
+            Period::Week => 7,
+        });
+        self.date = if forward {
+            self.date.checked_add_days(days)
+        } else {
+            self.date.checked_sub_days(days)
+        }
+        .unwrap_or(self.date);
+        self.selected = 0;
+        self.offset = 0;
+    }
+    /// Get first and last date of the shown period.
+    fn dates(&self) -> (NaiveDate, NaiveDate) {
+        match self.period {
+            Period::Day => (self.date, self.date),
+            Period::Week => {
+                let first = self.date
+                    - chrono::Duration::days(self.date.weekday().num_days_from_monday() as i64);
+                (first, first + chrono::Duration::days(6))
+            }
+        }
+    }
+    /// Get positions of all jobs (including deleted ones) which overlap the shown period.
+    fn visible(&self) -> Vec<usize> {
+        let (first, last) = self.dates();
+        let from = DateTime::from_local(&first.and_hms_opt(0, 0, 0).unwrap());
+        let to = DateTime::from_local(&last.succ_opt().unwrap().and_hms_opt(0, 0, 0).unwrap());
+        self.jobs
+            .iter()
+            .enumerate()
+            .filter(|(_, job)| job.start < to && job.end.unwrap_or(self.context.time()) > from)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+    /// Get position of the selected job.
+    fn selected_pos(&self) -> Option<usize> {
+        self.visible().get(self.selected).copied()
+    }
+    /// Draw the whole screen.
+    fn draw<W: Write>(&mut self, screen: &mut W) -> Result<(), Error> {
+        let (_, height) = termion::terminal_size()?;
+        let visible = self.visible();
+        self.selected = self.selected.min(visible.len().saturating_sub(1));
+
+        // title with period and sums
+        let (first, last) = self.dates();
+        let format = format!("%a {}", locale::date_order().display_format());
+        let title = match self.period {
+            Period::Day => first.format(&format).to_string(),
+            Period::Week => format!(
+                "Week {} from {} to {}",
+                first.iso_week().week(),
+                first.format(&format),
+                last.format(&format)
+            ),
+        };
+        let list = self.jobs.list(
+            &Positions::from_iter(
+                visible
+                    .iter()
+                    .copied()
+                    .filter(|pos| !self.jobs[*pos].is_deleted()),
+            ),
+            self.context,
+        );
+        write!(
+            screen,
+            "{}{}{}{}{} - {} job(s), {} hours{}",
+            clear::All,
+            cursor::Goto(1, 1),
+            style::Bold,
+            Fg(Yellow),
+            title,
+            list.len(),
+            format::hours_pure(list.hours_overall(self.context)),
+            style::Reset
+        )?;
+
+        // collect the lines of all jobs and remember where the selected one is
+        let mut lines = Vec::new();
+        let mut selection = (0, 0);
+        for (n, pos) in visible.iter().enumerate() {
+            let deleted = self.jobs[*pos].is_deleted();
+            let text = self
+                .jobs
+                .list(&Positions::from_iter([*pos]), self.context)
+                .to_string();
+            if n == self.selected {
+                selection.0 = lines.len();
+            }
+            for (l, line) in text.lines().skip(1).enumerate() {
+                let marker = if n == self.selected && !line.is_empty() {
+                    ">"
+                } else {
+                    " "
+                };
+                if deleted {
+                    lines.push(format!(
+                        "{marker} {}{line}{}{}",
+                        style::Faint,
+                        if l == 0 { " (deleted)" } else { "" },
+                        style::Reset
+                    ));
+                } else {
+                    lines.push(format!("{marker} {line}"));
+                }
+            }
+            if n == self.selected {
+                selection.1 = lines.len();
+            }
+        }
+        if lines.is_empty() {
+            lines.push("  No jobs in this period.".to_string());
+        }
+
+        // scroll to make the selected job visible
+        let rows = height.saturating_sub(3) as usize;
+        if selection.0 < self.offset {
+            self.offset = selection.0;
+        } else if selection.1 > self.offset + rows {
+            self.offset = selection.1.saturating_sub(rows);
+        }
+        for (row, line) in lines.iter().skip(self.offset).take(rows).enumerate() {
+            write!(screen, "{}{}", cursor::Goto(1, row as u16 + 3), line)?;
+        }
+
+        // status line
+        write!(
+            screen,
+            "{}{}",
+            cursor::Goto(1, height),
+            if self.status.is_empty() {
+                HELP.to_string()
+            } else {
+                format!("{}{}{}", Fg(LightYellow), self.status, Fg(Reset))
+            }
+        )?;
+        screen.flush()?;
+        Ok(())
+    }
+    /// Let user edit the selected job depending on the given key.
+    fn edit<W: Write>(&mut self, screen: &mut W, key: char) -> Result<(), Error> {
+        let pos = if let Some(pos) = self.selected_pos() {
+            pos
+        } else {
+            return Ok(());
+        };
+        let job = self.jobs[pos].clone();
+        let edit = |start, end, message, tags| Command::Edit {
+            pos: Some(pos),
+            start,
+            end,
+            message,
+            tags,
+            billable: None,
+        };
+        let command = match key {
+            's' => {
+                if let Some(start) =
+                    self.input(screen, "Start: ", &job.start.format(INPUT_FORMAT))?
+                {
+                    let start = self.parse_time(start, job.start)?;
+                    edit(Some(start), EndOrDuration::None, None, None)
+                } else {
+                    return Ok(());
+                }
+            }
+            'e' => {
+                let end = job.end.map(|end| end.format(INPUT_FORMAT));
+                if let Some(end) = self.input(screen, "End: ", &end.unwrap_or_default())? {
+                    let end = self.parse_time(end, job.end.unwrap_or(job.start))?;
+                    edit(None, EndOrDuration::End(end), None, None)
+                } else {
+                    return Ok(());
+                }
+            }
+            'm' => {
+                if let Some(message) = self.input(
+                    screen,
+                    "Message: ",
+                    &job.message.clone().unwrap_or_default(),
+                )? {
+                    edit(None, EndOrDuration::None, Some(Some(message)), None)
+                } else {
+                    return Ok(());
+                }
+            }
+            't' => {
+                if let Some(tags) = self.input(screen, "Tags: ", &job.tags.0.join(","))? {
+                    edit(
+                        None,
+                        EndOrDuration::None,
+                        None,
+                        Some(TagSet::from(&Some(tags))),
+                    )
+                } else {
+                    return Ok(());
+                }
+            }
+            'd' => Command::Delete {
+                range: Range::At(vec![pos]),
+                tags: None,
+            },
+            'u' => Command::Restore {
+                range: Range::At(vec![pos]),
+                tags: None,
+            },
+            _ => return Ok(()),
+        };
+        self.process(screen, command)
+    }
+    /// Parse date and time which user has entered.
+    fn parse_time(&self, input: String, base: DateTime) -> Result<DateTime, Error> {
+        Ok(PartialDateTime::parse(Some(input))?.into(base, self.context))
+    }
+    /// Process command on database and ask user about any warnings or a missing message.
+    fn process<W: Write>(&mut self, screen: &mut W, mut command: Command) -> Result<(), Error> {
+        // omit checks after user confirmed all warnings
+        let mut omit = false;
+        loop {
+            let checks = if omit {
+                self.checks.omit_warnings()
+            } else {
+                self.checks.clone()
+            };
+            match self
+                .jobs
+                .process(&mut std::io::sink(), &command, checks, self.context)
+            {
+                Ok(operation) => {
+                    self.status = operation
+                        .to_string()
+                        .lines()
+                        .next()
+                        .unwrap_or_default()
+                        .into();
+                    return Ok(());
+                }
+                Err(Error::Warnings(warnings)) => {
+                    for warning in warnings {
+                        if !self.confirm(screen, &format!("WARNING: {warning}"))? {
+                            return Err(Error::Cancel);
+                        }
+                    }
+                    omit = true;
+                }
+                Err(Error::EnterMessage) => {
+                    if let Some(message) = self.input(screen, "Message: ", "")? {
+                        command.set_message(message);
+                    } else {
+                        return Err(Error::Cancel);
+                    }
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+    /// Let user enter a single line of text in the status line.
+    fn input<W: Write>(
+        &self,
+        screen: &mut W,
+        prompt: &str,
+        text: &str,
+    ) -> Result<Option<String>, Error> {
+        let (_, height) = termion::terminal_size()?;
+        let mut text = text.to_string();
+        write!(screen, "{}", cursor::Show)?;
+        let result = loop {
+            write!(
+                screen,
+                "{}{}{}{prompt}{}{text}",
+                cursor::Goto(1, height),
+                clear::CurrentLine,
+                style::Bold,
+                style::Reset
+            )?;
+            screen.flush()?;
+            match std::io::stdin().keys().next() {
+                Some(Ok(Key::Char('\n'))) => break Some(text),
+                Some(Ok(Key::Esc)) | Some(Ok(Key::Ctrl('c'))) | None => break None,
+                Some(Ok(Key::Backspace)) => {
+                    text.pop();
+                }
+                Some(Ok(Key::Char(c))) => text.push(c),
+                Some(Err(err)) => return Err(Error::Io(err)),
+                _ => (),
+            }
+        };
+        write!(screen, "{}", cursor::Hide)?;
+        Ok(result)
+    }
+    /// Show a warning on the whole screen and let user confirm it.
+    fn confirm<W: Write>(&self, screen: &mut W, text: &str) -> Result<bool, Error> {
+        write!(screen, "{}{}", clear::All, cursor::Goto(1, 1))?;
+        for line in text.lines() {
+            write!(screen, "{line}\r\n")?;
+        }
+        write!(
+            screen,
+            "\r\n{}{}Do you still want to do this? (y/N){}{}",
+            style::Bold,
+            Fg(Yellow),
+            Fg(Reset),
+            style::Reset
+        )?;
+        screen.flush()?;
+        Ok(matches!(
+            std::io::stdin().keys().next(),
+            Some(Ok(Key::Char('y'))) | Some(Ok(Key::Char('Y')))
+        ))
+    }
+}
+
+/// Test navigation through periods.
+#[test]
+fn test_navigation() {
+    let context = Context::new_test("2023-2-1 12:00");
+    let mut jobs = Jobs::new();
+    let mut tui = Tui::new(&mut jobs, Checks::all(), &context);
+    let date = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+
+    assert_eq!(tui.dates(), (date("2023-2-1"), date("2023-2-1")));
+    tui.step(true);
+    assert_eq!(tui.dates(), (date("2023-2-2"), date("2023-2-2")));
+
+    // weeks begin on monday
+    tui.period = Period::Week;
+    assert_eq!(tui.dates(), (date("2023-1-30"), date("2023-2-5")));
+    tui.selected = 3;
+    tui.step(false);
+    assert_eq!(tui.dates(), (date("2023-1-23"), date("2023-1-29")));
+    assert_eq!(tui.selected, 0);
+}
+
+/// Test which jobs are shown and processing of changes.
+#[test]
+fn test_visible_and_process() {
+    let context = Context::new_test("2023-2-2 12:00");
+    let mut jobs = Jobs::new();
+    for (start, end) in [
+        ("2023-2-1 8:00", "2023-2-1 9:00"),
+        ("2023-2-2 8:00", "2023-2-2 9:00"),
+    ] {
+        let command = Command::Add {
+            start: start.into(),
+            end: end.into(),
+            message: Some(Some("work".into())),
+            tags: None,
+            billable: None,
+        };
+        jobs.process(&mut std::io::sink(), &command, Checks::all(), &context)
+            .unwrap();
+    }
+    let mut tui = Tui::new(&mut jobs, Checks::all(), &context);
+    assert_eq!(tui.visible(), [1]);
+    tui.period = Period::Week;
+    assert_eq!(tui.visible(), [0, 1]);
+
+    // process edit of the selected job
+    let edit = |message: &str| Command::Edit {
+        pos: Some(0),
+        start: None,
+        end: EndOrDuration::None,
+        message: Some(Some(message.into())),
+        tags: None,
+        billable: None,
+    };
+    tui.process(&mut std::io::sink(), edit("changed")).unwrap();
+    assert_eq!(tui.jobs[0].message, Some("changed".into()));
+    assert!(!tui.status.is_empty());
+
+    // given checks are used
+    tui.jobs
+        .process(
+            &mut std::io::sink(),
+            &Command::Invoice {
+                range: Range::All,
+                tags: None,
+                reference: Some("A-1".into()),
+            },
+            Checks::all(),
+            &context,
+        )
+        .unwrap();
+    assert!(matches!(
+        tui.process(&mut std::io::sink(), edit("again")),
+        Err(Error::Locked(_))
+    ));
+    tui.checks = Checks::all_but(Check::Locked);
+    tui.process(&mut std::io::sink(), edit("again")).unwrap();
+    assert_eq!(tui.jobs[0].message, Some("again".into()));
+}
@@ -1,12 +1,16 @@
 //! Check a job before insertion into job database.
 
 use super::prelude::*;
-use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
 /// Selectable checks
-#[derive(Hash, Eq, PartialEq, EnumIter, Debug)]
+#[derive(
+    Hash, Eq, PartialEq, Ord, PartialOrd, EnumIter, Debug, Clone, Copy, Serialize, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
 pub enum Check {
     /// Emit `Warning::Overlaps` if job would overlap another in time.
     Overlaps,
@@ -15,10 +19,104 @@ pub enum Check {
     /// Emit `Warning::ConfirmDeletion` if a job is about to be deleted.
     /// This check is done outside of `Checks`.
     ConfirmDeletion,
+    /// Emit `Warning::ConfirmModification` if multiple jobs are about to be modified.
+    /// This check is done outside of `Checks`.
+    ConfirmModification,
+    /// Return `Error::Locked` if an invoiced job would be changed or overlapped.
+    Locked,
+    /// Emit `Warning::MaxHours` if the work time of a day would exceed the configured maximum.
+    MaxHours,
+}
+
+impl Check {
+    /// Parse comma separated checks.
+    pub fn parse_list(checks: &str) -> Result<Vec<Self>, Error> {
+        checks.split(',').map(Self::parse).collect()
+    }
+    /// Parse check from a string like `overlaps`, `unknown-tags`, `confirm-deletion`,
+    /// `confirm-modification`, `locked` or `max-hours`.
+    pub fn parse(check: &str) -> Result<Self, Error> {
+        Ok(match check.to_lowercase().as_str() {
+            "overlaps" => Self::Overlaps,
+            "unknown-tags" => Self::UnknownTags,
+            "confirm-deletion" => Self::ConfirmDeletion,
+            "confirm-modification" => Self::ConfirmModification,
+            "locked" => Self::Locked,
+            "max-hours" => Self::MaxHours,
+            _ => return Err(Error::UnknownCheck(check.to_string())),
+        })
+    }
+}
+
+impl std::fmt::Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Overlaps => write!(f, "overlaps"),
+            Self::UnknownTags => write!(f, "unknown-tags"),
+            Self::ConfirmDeletion => write!(f, "confirm-deletion"),
+            Self::ConfirmModification => write!(f, "confirm-modification"),
+            Self::Locked => write!(f, "locked"),
+            Self::MaxHours => write!(f, "max-hours"),
+        }
+    }
+}
+
+/// How to handle a warning without asking the user.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Policy {
+    /// Fail with the warning (default).
+    #[default]
+    Fail,
+    /// Print the warning and continue.
+    Warn,
+    /// Continue silently.
+    Accept,
+}
+
+impl Policy {
+    /// Parse policy from a string like `fail`, `warn` or `accept`.
+    pub fn parse(policy: &str) -> Result<Self, Error> {
+        Ok(match policy.to_lowercase().as_str() {
+            "fail" => Self::Fail,
+            "warn" => Self::Warn,
+            "accept" => Self::Accept,
+            _ => return Err(Error::PolicyFormat(policy.to_string())),
+        })
+    }
+}
+
+/// Policies which decide how warnings of every check are handled in non-interactive mode.
+#[derive(Debug, Clone, Default)]
+pub struct Policies {
+    /// Policy of checks which are not listed
+    default: Policy,
+    /// Policies of specific checks
+    checks: HashMap<Check, Policy>,
+}
+
+impl Policies {
+    /// Parse comma separated policies like `warn` or `overlaps=accept,unknown-tags=warn`.
+    pub fn parse(policies: &str) -> Result<Self, Error> {
+        let mut result = Self::default();
+        for policy in policies.split(',') {
+            if let Some((check, policy)) = policy.split_once('=') {
+                result
+                    .checks
+                    .insert(Check::parse(check)?, Policy::parse(policy)?);
+            } else {
+                result.default = Policy::parse(policy)?;
+            }
+        }
+        Ok(result)
+    }
+    /// Get policy for the given check.
+    pub fn get(&self, check: Check) -> Policy {
+        *self.checks.get(&check).unwrap_or(&self.default)
+    }
 }
 
 /// A set of selectable checks.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Checks(HashSet<Check>);
 
 impl Checks {
@@ -36,7 +134,38 @@ impl Checks {
     }
     /// omit checks which lead to user confirmation
     pub fn no_confirm() -> Self {
-        Self(HashSet::from([Check::Overlaps]))
+        Self(HashSet::from([Check::Overlaps, Check::Locked]))
+    }
+    /// Omit all checks of this selection which ask the user to confirm a deletion or modification.
+    pub fn without_confirmation(&self) -> Self {
+        self.without(Check::ConfirmDeletion)
+            .without(Check::ConfirmModification)
+    }
+    /// Omit all checks of this selection which emit warnings (after user has confirmed them).
+    pub fn omit_warnings(&self) -> Self {
+        Self(
+            self.0
+                .iter()
+                .copied()
+                .filter(|c| *c == Check::Locked)
+                .collect(),
+        )
+    }
+    /// Enable (`true`) or disable (`false`) checks within this selection.
+    pub fn switch(&self, checks: &BTreeMap<Check, bool>) -> Self {
+        let mut result = self.0.clone();
+        for (check, enabled) in checks {
+            if *enabled {
+                result.insert(*check);
+            } else {
+                result.remove(check);
+            }
+        }
+        Self(result)
+    }
+    /// Select all checks of this selection but the given one.
+    pub fn without(&self, check: Check) -> Self {
+        Self(self.0.iter().copied().filter(|c| *c != check).collect())
     }
     /// Return `true` if the given check is included.
     pub fn has(&self, check: Check) -> bool {
@@ -64,16 +193,52 @@ impl Checks {
             }
         }
 
+        // there can be only one open job
+        if job.is_open() {
+            if let Some((n, open)) = jobs
+                .iter()
+                .enumerate()
+                .find(|(n, j)| Some(*n) != pos && !j.is_deleted() && j.is_open())
+            {
+                return Err(Error::OpenJob(n, Box::new(open.clone())));
+            }
+        }
+
+        // check if all breaks lie within the job
+        for b in &job.breaks {
+            if b.start <= job.start || job.end.is_some_and(|end| b.end.unwrap_or(b.start) > end) {
+                return Err(Error::BreakOutsideJob(b.start));
+            }
+        }
+
+        // check if an invoiced job would be changed or overlapped
+        if self.has(Check::Locked) {
+            if let Some(pos) = pos {
+                self.check_locked(jobs, [pos])?;
+            }
+            for (n, j) in jobs.iter().enumerate() {
+                if let Some(reference) = &j.invoice {
+                    if Some(n) != pos
+                        && !j.is_deleted()
+                        && j.end.is_some()
+                        && job.overlaps(j, context)?
+                    {
+                        return Err(Error::Locked(reference.clone()));
+                    }
+                }
+            }
+        }
+
         // check for overlapping
         if self.has(Check::Overlaps) {
-            let mut overlapping = JobList::new_from(jobs);
+            let mut overlapping = JobList::new_from(jobs, context);
             for (n, j) in jobs.iter().enumerate() {
                 if !j.is_deleted() {
                     if let Some(pos) = pos {
-                        if n != pos && job.overlaps(j, context) {
+                        if n != pos && job.overlaps(j, context)? {
                             overlapping.push(n, j);
                         }
-                    } else if job.overlaps(j, context) {
+                    } else if job.overlaps(j, context)? {
                         overlapping.push(n, j);
                     }
                 }
@@ -95,6 +260,11 @@ impl Checks {
             }
         }
 
+        // check for exceeding the maximum work time of any day
+        if self.has(Check::MaxHours) {
+            warnings.extend(Self::check_max_hours(jobs, pos, job, context));
+        }
+
         // check for colliding tags
         jobs.configuration.get_checked(&job.tags)?;
 
@@ -104,4 +274,67 @@ impl Checks {
         }
         Ok(())
     }
+    /// Sum up the hours of all days the given job lasts (together with the jobs of the same
+    /// relevant tag) like the report does and return a warning for each which exceeds the maximum.
+    /// Open jobs count until now.
+    fn check_max_hours(
+        jobs: &Jobs,
+        pos: Option<usize>,
+        job: &Job,
+        context: &Context,
+    ) -> Vec<Warning> {
+        let (tag, properties) = jobs.configuration.get_and_why(&job.tags);
+        let max_hours = match properties.max_hours {
+            Some(max_hours) => max_hours,
+            None => return Vec::new(),
+        };
+        let mut warnings = Vec::new();
+        // jobs crossing the day boundary count into both days
+        for (start, mut hours) in job.hours_per_day(properties, context) {
+            let date = start.date();
+            for (n, j) in jobs.iter().enumerate() {
+                if j.is_deleted()
+                    || Some(n) == pos
+                    || j.start.date() > date
+                    || j.end.unwrap_or(context.time()).date() < date
+                {
+                    continue;
+                }
+                let (other_tag, other_properties) = jobs.configuration.get_and_why(&j.tags);
+                if other_tag != tag {
+                    continue;
+                }
+                hours += j
+                    .hours_per_day(other_properties, context)
+                    .iter()
+                    .filter(|(start, _)| start.date() == date)
+                    .map(|(_, hours)| hours)
+                    .sum::<f64>();
+            }
+            if hours > max_hours as f64 {
+                warnings.push(Warning::MaxHours {
+                    date,
+                    tag: tag.clone(),
+                    hours,
+                    max_hours,
+                });
+            }
+        }
+        warnings
+    }
+    /// Return `Error::Locked` if any of the jobs at the given positions has been invoiced.
+    pub fn check_locked(
+        &self,
+        jobs: &Jobs,
+        positions: impl IntoIterator<Item = usize>,
+    ) -> Result<(), Error> {
+        if self.has(Check::Locked) {
+            for pos in positions {
+                if let Some(reference) = &jobs[pos].invoice {
+                    return Err(Error::Locked(reference.clone()));
+                }
+            }
+        }
+        Ok(())
+    }
 }
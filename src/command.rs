@@ -13,6 +13,19 @@ pub enum EndOrDuration {
     Duration(Duration),
 }
 
+/// Selects the job which message and tags shall be copied from.
+#[derive(PartialEq, Clone, Debug)]
+pub enum Template {
+    /// Last job in database
+    Last,
+    /// Job at position
+    Position(usize),
+    /// Latest job which message or tags contain the given term
+    Search(String),
+    /// Let user pick one of the recent jobs
+    Pick,
+}
+
 /// Commands which can be applied to jobber's database.
 #[derive(PartialEq, Clone, Debug)]
 pub enum Command {
@@ -22,6 +35,8 @@ pub enum Command {
         start: DateTime,
         message: Option<Option<String>>,
         tags: Option<TagSet>,
+        /// Overwrite if job is billable
+        billable: Option<bool>,
     },
     /// Add a new job by specifying start and end time if there is no open job.
     Add {
@@ -29,19 +44,27 @@ pub enum Command {
         end: DateTime,
         message: Option<Option<String>>,
         tags: Option<TagSet>,
+        /// Overwrite if job is billable
+        billable: Option<bool>,
     },
-    /// Like `Start` but re-use message an tags of previous job.
+    /// Like `Start` but re-use message an tags of a template job.
     Back {
         start: DateTime,
         message: Option<Option<String>>,
         tags: Option<TagSet>,
+        template: Template,
+        /// Overwrite if job is billable (or copy it from the template)
+        billable: Option<bool>,
     },
-    /// Like `Add` but re-use message an tags of previous job.
+    /// Like `Add` but re-use message an tags of a template job.
     BackAdd {
         start: DateTime,
         end: DateTime,
         message: Option<Option<String>>,
         tags: Option<TagSet>,
+        template: Template,
+        /// Overwrite if job is billable (or copy it from the template)
+        billable: Option<bool>,
     },
     /// End existing job by giving time.
     End {
@@ -49,11 +72,43 @@ pub enum Command {
         message: Option<Option<String>>,
         tags: Option<TagSet>,
     },
+    /// End the open job and start a new one at the same time.
+    /// Message and tags are used for the new job or copied from the open job (like in `Back`).
+    Switch {
+        time: DateTime,
+        message: Option<Option<String>>,
+        tags: Option<TagSet>,
+        /// message for the open job which will be ended
+        end_message: Option<String>,
+        /// Overwrite if the new job is billable (or copy it from the open job)
+        billable: Option<bool>,
+    },
+    /// Pause the open job by beginning a break at the given time.
+    Pause {
+        time: DateTime,
+    },
+    /// Resume the paused open job by ending the break at the given time.
+    Resume {
+        time: DateTime,
+    },
     /// List jobs
     List {
         range: Range,
         tags: Option<TagSet>,
     },
+    /// List gaps between jobs
+    Gaps {
+        range: Range,
+        tags: Option<TagSet>,
+    },
+    /// Scan database for problems
+    Doctor,
+    /// Check if the open job has been forgotten to end
+    Remind,
+    /// Print status of the open job in the given format
+    Status {
+        format: String,
+    },
     /// Report jobs
     Report {
         range: Range,
@@ -71,6 +126,7 @@ pub enum Command {
     SetConfiguration {
         tags: Option<TagSet>,
         update: Properties,
+        settings: Settings,
     },
     /// Import CSV database of legacy Ruby *jobber* version
     LegacyImport {
@@ -88,12 +144,56 @@ pub enum Command {
         end: EndOrDuration,
         message: Option<Option<String>>,
         tags: Option<TagSet>,
+        /// Overwrite if job is billable
+        billable: Option<bool>,
+    },
+    /// Edit all jobs within a range which have the given tags.
+    EditMany {
+        range: Range,
+        /// Only edit jobs with these tags
+        filter: Option<TagSet>,
+        /// Set or modify tags
+        tags: Option<TagSet>,
+        /// Replace message
+        message: Option<String>,
+        /// Shift start and end by minutes
+        shift: Option<i64>,
+        /// Overwrite if jobs are billable
+        billable: Option<bool>,
+    },
+    /// Cut an existing job into two at the given time.
+    /// Message and tags are used for the second part or copied from the original job.
+    Split {
+        pos: usize,
+        at: DateTime,
+        message: Option<Option<String>>,
+        tags: Option<TagSet>,
+    },
+    /// Combine following jobs into one.
+    Merge {
+        range: Range,
+        message: Option<Option<String>>,
+    },
+    /// Drop breaks which lie outside of an existing job and cut those which last beyond it's end.
+    TrimBreaks {
+        pos: usize,
     },
     /// Delete an existing job.
     Delete {
         range: Range,
         tags: Option<TagSet>,
     },
+    /// Restore deleted jobs.
+    Restore {
+        range: Range,
+        tags: Option<TagSet>,
+    },
+    /// Mark jobs as invoiced by the given reference or remove the invoice if `None`.
+    Invoice {
+        range: Range,
+        tags: Option<TagSet>,
+        reference: Option<String>,
+    },
 }
 
 impl Command {
@@ -101,33 +201,64 @@ impl Command {
     pub fn set_message(&mut self, new_message: String) {
         match *self {
             Command::Start {
-                start: _,
-                ref mut message,
-                tags: _,
+                ref mut message, ..
             } => *message = Some(Some(new_message)),
             Command::Add {
-                start: _,
-                end: _,
-                ref mut message,
-                tags: _,
+                ref mut message, ..
             } => *message = Some(Some(new_message)),
             Command::Back {
                 start: _,
                 ref mut message,
                 tags: _,
+                template: _,
+                billable: _,
             } => *message = Some(Some(new_message)),
             Command::BackAdd {
                 start: _,
                 end: _,
                 ref mut message,
                 tags: _,
+                template: _,
+                billable: _,
             } => *message = Some(Some(new_message)),
             Command::End {
                 end: _,
                 ref mut message,
                 tags: _,
             } => *message = Some(Some(new_message)),
+            Command::Edit {
+                ref mut message, ..
+            } => *message = Some(Some(new_message)),
+            Command::Switch {
+                ref mut end_message,
+                ..
+            } => *end_message = Some(new_message),
+            Command::Split {
+                ref mut message, ..
+            } => *message = Some(Some(new_message)),
+            Command::Merge {
+                ref mut message, ..
+            } => *message = Some(Some(new_message)),
             _ => panic!("try to set message of command which has no message"),
         }
     }
+    /// Return template selection if this command uses one.
+    pub fn template(&self) -> Option<&Template> {
+        match self {
+            Command::Back { template, .. } | Command::BackAdd { template, .. } => Some(template),
+            _ => None,
+        }
+    }
+    /// Change template selection of this command (if it uses one).
+    pub fn set_template(&mut self, new_template: Template) {
+        match *self {
+            Command::Back {
+                ref mut template, ..
+            }
+            | Command::BackAdd {
+                ref mut template, ..
+            } => *template = new_template,
+            _ => panic!("try to set template of command which has no template"),
+        }
+    }
 }
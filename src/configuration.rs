@@ -2,7 +2,7 @@
 
 use crate::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Configuration of a *jobber* database.
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -11,6 +11,9 @@ pub struct Configuration {
     pub base: Properties,
     /// Configuration by tag
     pub tags: HashMap<String, Properties>,
+    /// Settings which are independent from tags
+    #[serde(default)]
+    pub settings: Settings,
 }
 
 impl Configuration {
@@ -33,6 +36,10 @@ impl Configuration {
         }
         modified
     }
+    /// Partially overwrite settings.
+    pub fn set_settings(&mut self, update: &Settings) -> bool {
+        self.settings.update(update.clone())
+    }
     /// get properties for the given tags and additionally return which tag was relevant
     pub fn get_and_why(&self, tags: &TagSet) -> (Option<String>, &Properties) {
         for tag in &tags.0 {
@@ -76,9 +83,27 @@ pub struct Properties {
     pub rate: Option<f64>,
     /// Maximum work hours per day
     pub max_hours: Option<u32>,
+    /// Minimum billable hours per job
+    pub min_hours: Option<f64>,
+    /// Whether jobs are billable by default
+    pub billable: Option<bool>,
+    /// How hours are rounded to the resolution
+    pub rounding: Option<Rounding>,
+    /// Whether hours are rounded per job, per day or per invoice
+    pub rounding_level: Option<RoundingLevel>,
 }
 
 impl Properties {
+    /// Return `true` if no property is set.
+    pub fn is_empty(&self) -> bool {
+        self.resolution.is_none()
+            && self.rate.is_none()
+            && self.max_hours.is_none()
+            && self.min_hours.is_none()
+            && self.billable.is_none()
+            && self.rounding.is_none()
+            && self.rounding_level.is_none()
+    }
     /// Update properties.
     /// # Arguments
     /// - `properties`: Properties to overwrite (empty properties will be ignored)
@@ -98,8 +123,36 @@ impl Properties {
             self.max_hours = Some(max_hours);
             modified = true;
         }
+        if let Some(min_hours) = properties.min_hours {
+            self.min_hours = Some(min_hours);
+            modified = true;
+        }
+        if let Some(billable) = properties.billable {
+            self.billable = Some(billable);
+            modified = true;
+        }
+        if let Some(rounding) = properties.rounding {
+            self.rounding = Some(rounding);
+            modified = true;
+        }
+        if let Some(rounding_level) = properties.rounding_level {
+            self.rounding_level = Some(rounding_level);
+            modified = true;
+        }
         modified
     }
+    /// Get the level at which hours are rounded.
+    pub fn rounding_level(&self) -> RoundingLevel {
+        self.rounding_level.unwrap_or_default()
+    }
+    /// Round hours to the resolution (or to hundredths if no resolution is set).
+    pub fn round(&self, hours: f64) -> f64 {
+        if let Some(resolution) = self.resolution {
+            self.rounding.unwrap_or_default().round(hours / resolution) * resolution
+        } else {
+            (hours / 0.01).round() * 0.01
+        }
+    }
 }
 
 impl Default for Properties {
@@ -108,6 +161,10 @@ impl Default for Properties {
             resolution: Some(0.25),
             rate: None,
             max_hours: None,
+            min_hours: None,
+            billable: None,
+            rounding: None,
+            rounding_level: None,
         }
     }
 }
@@ -123,6 +180,366 @@ impl std::fmt::Display for Properties {
         if let Some(max_hours) = self.max_hours {
             writeln!(f, "Maximum work time: {} hours", max_hours)?
         };
+        if let Some(min_hours) = self.min_hours {
+            writeln!(f, "Minimum billable time: {} hours", min_hours)?
+        };
+        if let Some(billable) = self.billable {
+            writeln!(f, "Billable: {}", if billable { "yes" } else { "no" })?
+        };
+        if let Some(rounding) = self.rounding {
+            writeln!(f, "Rounding: {}", rounding)?
+        };
+        if let Some(rounding_level) = self.rounding_level {
+            writeln!(f, "Rounding level: {}", rounding_level)?
+        };
+        Ok(())
+    }
+}
+
+/// How hours are rounded to the resolution.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Rounding {
+    /// Round up to the next step
+    #[default]
+    Up,
+    /// Round to the nearest step
+    Nearest,
+    /// Round down to the previous step
+    Down,
+}
+
+impl Rounding {
+    /// Parse rounding from a string like `up`, `nearest` or `down`.
+    pub fn parse(rounding: &str) -> Result<Self, Error> {
+        Ok(match rounding.to_lowercase().as_str() {
+            "up" => Self::Up,
+            "nearest" => Self::Nearest,
+            "down" => Self::Down,
+            _ => return Err(Error::RoundingFormat(rounding.to_string())),
+        })
+    }
+    /// Round a number of steps.
+    pub fn round(&self, steps: f64) -> f64 {
+        match self {
+            Self::Up => steps.ceil(),
+            Self::Nearest => steps.round(),
+            Self::Down => steps.floor(),
+        }
+    }
+}
+
+impl std::fmt::Display for Rounding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Up => write!(f, "up"),
+            Self::Nearest => write!(f, "nearest"),
+            Self::Down => write!(f, "down"),
+        }
+    }
+}
+
+/// Which amount of hours is rounded to the resolution.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RoundingLevel {
+    /// Round the hours of every single job
+    #[default]
+    Job,
+    /// Round the sum of hours of every day
+    Day,
+    /// Round the sum of all hours
+    Invoice,
+}
+
+impl RoundingLevel {
+    /// Parse rounding level from a string like `job`, `day` or `invoice`.
+    pub fn parse(level: &str) -> Result<Self, Error> {
+        Ok(match level.to_lowercase().as_str() {
+            "job" => Self::Job,
+            "day" => Self::Day,
+            "invoice" => Self::Invoice,
+            _ => return Err(Error::RoundingLevelFormat(level.to_string())),
+        })
+    }
+}
+
+impl std::fmt::Display for RoundingLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Job => write!(f, "per job"),
+            Self::Day => write!(f, "per day"),
+            Self::Invoice => write!(f, "per invoice"),
+        }
+    }
+}
+
+/// Order of day, month and year when reading or writing dates.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DateOrder {
+    /// Month first (e.g. `1/31/2023`)
+    #[default]
+    MDY,
+    /// Day first (e.g. `31.1.2023` or `31/1/2023`)
+    DMY,
+    /// Year first (e.g. `2023-1-31`)
+    YMD,
+}
+
+impl DateOrder {
+    /// Parse date order from a string like `mdy`, `dmy` or `ymd`.
+    pub fn parse(order: &str) -> Result<Self, Error> {
+        Ok(match order.to_lowercase().as_str() {
+            "mdy" => Self::MDY,
+            "dmy" => Self::DMY,
+            "ymd" => Self::YMD,
+            _ => return Err(Error::DateOrderFormat(order.to_string())),
+        })
+    }
+    /// Format string to display a date.
+    pub fn display_format(&self) -> &'static str {
+        match self {
+            Self::MDY => "%b %d %Y",
+            Self::DMY => "%d %b %Y",
+            Self::YMD => "%Y-%m-%d",
+        }
+    }
+    /// Format string to export a date.
+    pub fn export_format(&self) -> &'static str {
+        match self {
+            Self::MDY => "%m/%d/%Y",
+            Self::DMY => "%d.%m.%Y",
+            Self::YMD => "%Y-%m-%d",
+        }
+    }
+}
+
+impl std::fmt::Display for DateOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MDY => write!(f, "month/day/year"),
+            Self::DMY => write!(f, "day.month.year"),
+            Self::YMD => write!(f, "year-month-day"),
+        }
+    }
+}
+
+/// Clock to use when writing times.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum Clock {
+    /// 12-hour clock with AM/PM
+    #[serde(rename = "12h")]
+    H12,
+    /// 24-hour clock
+    #[default]
+    #[serde(rename = "24h")]
+    H24,
+}
+
+impl Clock {
+    /// Parse clock from a string like `12` or `24h`.
+    pub fn parse(clock: &str) -> Result<Self, Error> {
+        Ok(match clock.to_lowercase().as_str() {
+            "12" | "12h" => Self::H12,
+            "24" | "24h" => Self::H24,
+            _ => return Err(Error::ClockFormat(clock.to_string())),
+        })
+    }
+    /// Format string to write a time.
+    pub fn time_format(&self) -> &'static str {
+        match self {
+            Self::H12 => "%I:%M %p",
+            Self::H24 => "%H:%M",
+        }
+    }
+}
+
+impl std::fmt::Display for Clock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::H12 => write!(f, "12 hours"),
+            Self::H24 => write!(f, "24 hours"),
+        }
+    }
+}
+
+/// Daily working hours which bound the search for gaps between jobs.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct WorkHours {
+    /// Time of day when work begins
+    pub start: chrono::NaiveTime,
+    /// Time of day when work ends
+    pub end: chrono::NaiveTime,
+}
+
+impl WorkHours {
+    /// Parse working hours from a string like `8:00-17:00`.
+    pub fn parse(hours: &str) -> Result<Self, Error> {
+        let err = || Error::WorkHoursFormat(hours.to_string());
+        let (start, end) = hours.split_once('-').ok_or_else(err)?;
+        let parse = |time: &str| chrono::NaiveTime::parse_from_str(time.trim(), "%H:%M");
+        let start = parse(start).map_err(|_| err())?;
+        let end = parse(end).map_err(|_| err())?;
+        if end <= start {
+            return Err(err());
+        }
+        Ok(Self { start, end })
+    }
+}
+
+impl Default for WorkHours {
+    fn default() -> Self {
+        Self {
+            start: chrono::NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            end: chrono::NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+        }
+    }
+}
+
+impl std::fmt::Display for WorkHours {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} - {}",
+            self.start.format(locale::clock().time_format()),
+            self.end.format(locale::clock().time_format())
+        )
+    }
+}
+
+/// Check if the given format of date and time (like `%Y-%m-%d %H:%M`) can be used to export.
+pub fn check_export_format(format: &str) -> Result<(), Error> {
+    use chrono::format::{Item, StrftimeItems};
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(Error::ExportFormat(format.to_string()));
+    }
+    Ok(())
+}
+
+/// Settings within the database configuration which do not depend on tags.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct Settings {
+    /// Preferred order of day, month and year
+    pub date_order: Option<DateOrder>,
+    /// Preferred clock
+    pub clock: Option<Clock>,
+    /// Format of date and time in CSV export
+    pub export_format: Option<String>,
+    /// Time zone (IANA name or `local`) in which times are read, written, split and grouped by day
+    pub time_zone: Option<String>,
+    /// Daily working hours in which gaps between jobs are searched
+    pub work_hours: Option<WorkHours>,
+    /// Minimum length of a gap between jobs in minutes
+    pub min_gap: Option<i64>,
+    /// Checks which are enabled (`true`) or disabled (`false`)
+    pub checks: Option<BTreeMap<Check, bool>>,
+    /// Time at which a work day ends and the next one begins (midnight if not set)
+    pub day_boundary: Option<chrono::NaiveTime>,
+}
+
+impl Settings {
+    /// Create settings where nothing is set.
+    pub const fn new() -> Self {
+        Self {
+            date_order: None,
+            clock: None,
+            export_format: None,
+            time_zone: None,
+            work_hours: None,
+            min_gap: None,
+            checks: None,
+            day_boundary: None,
+        }
+    }
+    /// Return `true` if no setting is set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::new()
+    }
+    /// Update settings.
+    /// # Arguments
+    /// - `settings`: Settings to overwrite (empty settings will be ignored)
+    /// # Return Value
+    /// Returns `true` if any modification was made.
+    pub fn update(&mut self, settings: Settings) -> bool {
+        let mut modified = false;
+        if let Some(date_order) = settings.date_order {
+            self.date_order = Some(date_order);
+            modified = true;
+        }
+        if let Some(clock) = settings.clock {
+            self.clock = Some(clock);
+            modified = true;
+        }
+        if let Some(export_format) = settings.export_format {
+            self.export_format = Some(export_format);
+            modified = true;
+        }
+        if let Some(time_zone) = settings.time_zone {
+            self.time_zone = Some(time_zone);
+            modified = true;
+        }
+        if let Some(work_hours) = settings.work_hours {
+            self.work_hours = Some(work_hours);
+            modified = true;
+        }
+        if let Some(min_gap) = settings.min_gap {
+            self.min_gap = Some(min_gap);
+            modified = true;
+        }
+        if let Some(checks) = settings.checks {
+            self.checks.get_or_insert_with(BTreeMap::new).extend(checks);
+            modified = true;
+        }
+        if let Some(day_boundary) = settings.day_boundary {
+            self.day_boundary = Some(day_boundary);
+            modified = true;
+        }
+        modified
+    }
+}
+
+impl std::fmt::Display for Settings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(date_order) = self.date_order {
+            writeln!(f, "Date order: {}", date_order)?;
+        }
+        if let Some(clock) = self.clock {
+            writeln!(f, "Clock: {}", clock)?;
+        }
+        if let Some(export_format) = &self.export_format {
+            writeln!(f, "Export format: {}", export_format)?;
+        }
+        if let Some(time_zone) = &self.time_zone {
+            writeln!(f, "Time zone: {}", time_zone)?;
+        }
+        if let Some(work_hours) = self.work_hours {
+            writeln!(f, "Work hours: {}", work_hours)?;
+        }
+        if let Some(min_gap) = self.min_gap {
+            writeln!(f, "Minimum gap: {} minutes", min_gap)?;
+        }
+        if let Some(checks) = &self.checks {
+            writeln!(
+                f,
+                "Checks: {}",
+                checks
+                    .iter()
+                    .map(|(check, enabled)| format!(
+                        "{check} {}",
+                        if *enabled { "enabled" } else { "disabled" }
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        if let Some(day_boundary) = self.day_boundary {
+            writeln!(
+                f,
+                "Day boundary: {}",
+                day_boundary.format(locale::clock().time_format())
+            )?;
+        }
         Ok(())
     }
 }
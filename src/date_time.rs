@@ -1,9 +1,81 @@
 //! Date and time
 
 use super::prelude::*;
-use chrono::{Datelike, Local, NaiveDateTime, TimeZone, Utc};
+use chrono::{Datelike, Local, LocalResult, NaiveDateTime, Offset, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 
+/// Time zone to convert between UTC and local date and time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Zone {
+    /// Time zone of the system.
+    Local,
+    /// Time zone from the IANA database (like `Europe/Berlin`).
+    Tz(chrono_tz::Tz),
+}
+
+impl Zone {
+    /// Parse time zone from `local` or an IANA time zone name.
+    pub fn parse(zone: &str) -> Result<Self, Error> {
+        if zone.to_lowercase() == "local" {
+            Ok(Self::Local)
+        } else {
+            Ok(Self::Tz(
+                zone.parse()
+                    .map_err(|_| Error::TimeZoneFormat(zone.to_string()))?,
+            ))
+        }
+    }
+    /// Get UTC offset in seconds at the given time.
+    pub fn offset(&self, utc: &chrono::DateTime<Utc>) -> i32 {
+        match self {
+            Self::Local => Local.offset_from_utc_datetime(&utc.naive_utc()).fix(),
+            Self::Tz(tz) => tz.offset_from_utc_datetime(&utc.naive_utc()).fix(),
+        }
+        .local_minus_utc()
+    }
+    /// Convert UTC into naive local date and time.
+    pub fn to_local(self, utc: &chrono::DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            Self::Local => Local.from_utc_datetime(&utc.naive_utc()).naive_local(),
+            Self::Tz(tz) => tz.from_utc_datetime(&utc.naive_utc()).naive_local(),
+        }
+    }
+    /// Convert naive local date and time into UTC.
+    pub fn to_utc(self, local: &NaiveDateTime) -> chrono::DateTime<Utc> {
+        match self {
+            Self::Local => Self::resolve(&Local, local),
+            Self::Tz(tz) => Self::resolve(&tz, local),
+        }
+    }
+    /// Resolve local date and time even if it is ambiguous or skipped because of daylight saving time.
+    fn resolve<Z: TimeZone>(zone: &Z, local: &NaiveDateTime) -> chrono::DateTime<Utc> {
+        match zone.from_local_datetime(local) {
+            LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => dt.with_timezone(&Utc),
+            // time was skipped by switching to daylight saving time so take the time one hour later
+            LocalResult::None => Self::resolve(zone, &(*local + chrono::Duration::hours(1))),
+        }
+    }
+}
+
+impl std::fmt::Display for Zone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Local => write!(f, "local"),
+            Self::Tz(tz) => write!(f, "{}", tz.name()),
+        }
+    }
+}
+
+/// Format UTC offset (in seconds) like `UTC+02:00`.
+pub fn format_offset(offset: i32) -> String {
+    format!(
+        "UTC{}{:02}:{:02}",
+        if offset < 0 { '-' } else { '+' },
+        offset.abs() / 3600,
+        offset.abs() % 3600 / 60
+    )
+}
+
 /// Date and time (hours & minutes).
 #[derive(Clone, Copy, PartialOrd, PartialEq, Serialize, Deserialize, Ord, Eq)]
 #[serde(transparent)]
@@ -11,7 +83,11 @@ pub struct DateTime(chrono::DateTime<Utc>);
 
 impl std::fmt::Display for DateTime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.into_local().format("%a %b %d %Y, %H:%M"))
+        write!(
+            f,
+            "{}",
+            self.into_local().format(&locale::date_time_format())
+        )
     }
 }
 
@@ -26,42 +102,64 @@ impl DateTime {
     pub fn now() -> Self {
         DateTime(Utc::now())
     }
-    /// Return year.
+    /// Return year of the work day (in reporting time zone).
     pub fn year(&self) -> i32 {
-        self.0.year()
+        self.work_day_time().year()
     }
-    /// Return month.
+    /// Return month of the work day (in reporting time zone).
     pub fn month(&self) -> u32 {
-        self.0.month()
+        self.work_day_time().month()
     }
-    /// Return day.
+    /// Return day of the work day (in reporting time zone).
     pub fn day(&self) -> u32 {
-        self.0.day()
+        self.work_day_time().day()
     }
-    /// Return date only.
+    /// Return date of the work day only.
     pub fn date(&self) -> Date {
+        Date(self.work_day_time().date())
+    }
+    /// Return calendar date only (regardless of the day boundary).
+    pub fn local_date(&self) -> Date {
         Date(self.into_local().date())
     }
-    /// Convert into naive local date and time.
+    /// Convert into naive local date and time shifted by the day boundary so that the date
+    /// is the one of the work day.
+    fn work_day_time(&self) -> NaiveDateTime {
+        self.into_local() - locale::day_boundary()
+    }
+    /// Convert into naive local date and time of the reporting time zone.
     pub fn into_local(&self) -> NaiveDateTime {
-        Local.from_utc_datetime(&self.0.naive_local()).naive_local()
+        locale::zone().to_local(&self.0)
     }
-    /// Convert from naive local date and time.
+    /// Convert from naive local date and time of the reporting time zone.
     pub fn from_local(local: &NaiveDateTime) -> Self {
-        let local = Local.from_local_datetime(local).unwrap();
-        Self(chrono::DateTime::from(local))
+        Self(locale::zone().to_utc(local))
+    }
+    /// Convert into naive local date and time at the given UTC offset (in seconds).
+    pub fn into_offset(&self, offset: i32) -> NaiveDateTime {
+        self.0.naive_utc() + chrono::Duration::seconds(offset as i64)
+    }
+    /// Format as RFC 3339 with the UTC offset of the reporting time zone.
+    pub fn to_rfc3339(&self) -> String {
+        if let Some(offset) = chrono::FixedOffset::east_opt(self.offset()) {
+            self.0
+                .with_timezone(&offset)
+                .to_rfc3339_opts(chrono::SecondsFormat::Secs, false)
+        } else {
+            self.0.to_rfc3339_opts(chrono::SecondsFormat::Secs, false)
+        }
+    }
+    /// Return the UTC offset (in seconds) of the reporting time zone at this time.
+    pub fn offset(&self) -> i32 {
+        locale::zone().offset(&self.0)
+    }
+    /// Return the UTC offset (in seconds) of the system's time zone at this time.
+    pub fn system_offset(&self) -> i32 {
+        Zone::Local.offset(&self.0)
     }
     /// Convert from naive local date and time string.
     fn from_local_str(local: &str) -> Self {
-        Self(
-            Utc.from_local_datetime(
-                &Local
-                    .datetime_from_str(local, "%Y-%m-%d %H:%M")
-                    .unwrap()
-                    .naive_utc(),
-            )
-            .unwrap(),
-        )
+        Self::from_local(&NaiveDateTime::parse_from_str(local, "%Y-%m-%d %H:%M").unwrap())
     }
     /// Convert from naive RFC3339 date and time.
     pub fn from_rfc3339(rfc3339: &str) -> Result<Self, Error> {
@@ -164,11 +262,7 @@ impl std::ops::Sub<chrono::Duration> for DateTime {
 impl std::ops::Sub for &DateTime {
     type Output = Duration;
     fn sub(self, other: &DateTime) -> Self::Output {
-        let minutes = (self.0 - other.0).num_minutes();
-        Duration::HM {
-            hours: minutes / 60,
-            minutes: minutes % 60,
-        }
+        Duration::minutes((self.0 - other.0).num_minutes())
     }
 }
 
@@ -177,28 +271,40 @@ impl std::ops::Sub for &DateTime {
 pub struct Date(chrono::NaiveDate);
 
 impl Date {
+    /// Return the beginning of the first work day in this month.
     pub fn first_day_of_month(&self) -> DateTime {
-        DateTime::from_local(
-            &chrono::NaiveDate::from_ymd_opt(self.0.year(), self.0.month(), 1)
-                .unwrap()
-                .and_hms_opt(0, 0, 0)
-                .unwrap(),
-        )
+        Self::begin(self.0.with_day(1).unwrap())
     }
+    /// Return the beginning of the first work day in the month before.
     pub fn first_day_of_previous_month(&self) -> DateTime {
-        DateTime::from_local(
-            &chrono::NaiveDate::from_ymd_opt(self.0.year(), self.0.month() - 1, 1)
+        Self::begin(
+            self.0
+                .with_day(1)
                 .unwrap()
-                .and_hms_opt(0, 0, 0)
+                .checked_sub_months(chrono::Months::new(1))
                 .unwrap(),
         )
     }
+    /// Return date and time at the given time of day within this work day.
+    pub fn at(&self, time: chrono::NaiveTime) -> DateTime {
+        let local = self.0.and_time(time);
+        DateTime::from_local(&if time.signed_duration_since(chrono::NaiveTime::MIN)
+            < locale::day_boundary()
+        {
+            local + chrono::Duration::days(1)
+        } else {
+            local
+        })
+    }
+    /// Return the beginning of the work day at the given date.
+    fn begin(date: chrono::NaiveDate) -> DateTime {
+        DateTime::from_local(&(date.and_hms_opt(0, 0, 0).unwrap() + locale::day_boundary()))
+    }
 }
 
 impl From<DateTime> for Date {
     fn from(value: DateTime) -> Self {
-        let datetime: chrono::DateTime<Utc> = value.into();
-        Date(datetime.date_naive())
+        value.date()
     }
 }
 
@@ -207,3 +313,62 @@ impl std::fmt::Display for Date {
         write!(f, "{}", self.0)
     }
 }
+
+#[test]
+fn test_zone() {
+    let berlin = Zone::parse("Europe/Berlin").unwrap();
+    assert_eq!(Zone::parse("local").unwrap(), Zone::Local);
+    assert!(Zone::parse("Mars/Olympus").is_err());
+
+    let local = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+    let utc = |s| local(s).and_utc();
+
+    // winter & summer time
+    assert_eq!(
+        berlin.to_utc(&local("2023-01-15 12:00")),
+        utc("2023-01-15 11:00")
+    );
+    assert_eq!(
+        berlin.to_utc(&local("2023-07-15 12:00")),
+        utc("2023-07-15 10:00")
+    );
+    assert_eq!(
+        berlin.to_local(&utc("2023-07-15 10:00")),
+        local("2023-07-15 12:00")
+    );
+    assert_eq!(berlin.offset(&utc("2023-07-15 10:00")), 7200);
+
+    // skipped hour when switching to daylight saving time
+    assert_eq!(
+        berlin.to_utc(&local("2023-03-26 02:30")),
+        utc("2023-03-26 01:30")
+    );
+    // repeated hour when switching back takes the earliest
+    assert_eq!(
+        berlin.to_utc(&local("2023-10-29 02:30")),
+        utc("2023-10-29 00:30")
+    );
+
+    assert_eq!(format_offset(7200), "UTC+02:00");
+    assert_eq!(format_offset(-34200), "UTC-09:30");
+}
+
+#[test]
+fn test_first_day_of_month() {
+    let date = |y, m, d| Date(chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap());
+    let local = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+
+    assert_eq!(
+        date(2023, 3, 15).first_day_of_month().into_local(),
+        local("2023-03-01 00:00")
+    );
+    assert_eq!(
+        date(2023, 3, 15).first_day_of_previous_month().into_local(),
+        local("2023-02-01 00:00")
+    );
+    // previous month of january is in the year before
+    assert_eq!(
+        date(2023, 1, 31).first_day_of_previous_month().into_local(),
+        local("2022-12-01 00:00")
+    );
+}
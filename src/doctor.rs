@@ -0,0 +1,288 @@
+//! Scan a whole database for inconsistencies which checks would reject at insertion time.
+
+use super::prelude::*;
+
+/// Inconsistency within the database.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Problem {
+    /// Job at position `usize` ends before it starts.
+    EndBeforeStart(usize, DateTime, DateTime),
+    /// Jobs at both positions overlap (`true` if the second is a duplicate of the first).
+    Overlaps(usize, usize, bool),
+    /// Job at position `usize` is open while a later job has been started at the given time.
+    OpenJobs(usize, DateTime),
+    /// Job at position `usize` uses tags which have different configurations.
+    TagCollision(usize, TagSet),
+    /// Job at position `usize` has a break (beginning at the given time) which does not lie
+    /// within the job (`true` if the breaks can be trimmed unambiguously).
+    BreakOutsideJob(usize, DateTime, bool),
+}
+
+/// Automated fix of a [Problem].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Fix {
+    /// Delete the job at position `usize`.
+    Delete(usize),
+    /// End the job at position `usize` at the given time.
+    End(usize, DateTime),
+    /// Drop or cut the breaks of the job at position `usize` which lie outside of it.
+    TrimBreaks(usize),
+}
+
+impl Problem {
+    /// Return a fix if there is an unambiguous one.
+    pub fn fix(&self) -> Option<Fix> {
+        match self {
+            Problem::Overlaps(_, duplicate, true) => Some(Fix::Delete(*duplicate)),
+            Problem::OpenJobs(pos, next) => Some(Fix::End(*pos, *next)),
+            Problem::BreakOutsideJob(pos, _, true) => Some(Fix::TrimBreaks(*pos)),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::EndBeforeStart(pos, start, end) => write!(
+                f,
+                "Job at position {} ends ({}) before it starts ({}).",
+                pos + 1,
+                format::end(end),
+                format::start(start)
+            ),
+            Problem::Overlaps(pos, other, false) => {
+                write!(
+                    f,
+                    "Jobs at positions {} and {} overlap.",
+                    pos + 1,
+                    other + 1
+                )
+            }
+            Problem::Overlaps(pos, other, true) => write!(
+                f,
+                "Job at position {} is a duplicate of the job at position {}.",
+                other + 1,
+                pos + 1
+            ),
+            Problem::OpenJobs(pos, _) => write!(
+                f,
+                "Job at position {} is still open although a later job has been started.",
+                pos + 1
+            ),
+            Problem::TagCollision(pos, tags) => write!(
+                f,
+                "Job at position {} uses tags {tags} which have different configurations.",
+                pos + 1
+            ),
+            Problem::BreakOutsideJob(pos, start, _) => write!(
+                f,
+                "Job at position {} has a break ({}) which does not lie within the job.",
+                pos + 1,
+                format::start(start)
+            ),
+        }
+    }
+}
+
+impl Fix {
+    /// Create a command which applies this fix.
+    pub fn command(&self) -> Command {
+        match self {
+            Fix::Delete(pos) => Command::Delete {
+                range: Range::At(vec![*pos]),
+                tags: None,
+            },
+            Fix::End(pos, end) => Command::Edit {
+                pos: Some(*pos),
+                start: None,
+                end: EndOrDuration::End(*end),
+                message: None,
+                tags: None,
+                billable: None,
+            },
+            Fix::TrimBreaks(pos) => Command::TrimBreaks { pos: *pos },
+        }
+    }
+}
+
+impl std::fmt::Display for Fix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Fix::Delete(pos) => write!(f, "Delete job at position {}", pos + 1),
+            Fix::End(pos, end) => {
+                write!(f, "End job at position {} at {}", pos + 1, format::end(end))
+            }
+            Fix::TrimBreaks(pos) => write!(
+                f,
+                "Drop or cut the breaks of job at position {} which lie outside of it",
+                pos + 1
+            ),
+        }
+    }
+}
+
+/// Find all problems within the (not deleted) jobs of a database.
+pub fn diagnose(jobs: &Jobs, context: &Context) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let list: Vec<(usize, &Job)> = jobs
+        .iter()
+        .enumerate()
+        .filter(|(_, job)| !job.is_deleted())
+        .collect();
+
+    // check for temporal plausibility and colliding tags
+    for (pos, job) in &list {
+        if let Some(end) = job.end {
+            if job.start >= end {
+                problems.push(Problem::EndBeforeStart(*pos, job.start, end));
+            }
+        }
+        if let Err(Error::TagCollision(tags)) = jobs.configuration.get_checked(&job.tags) {
+            problems.push(Problem::TagCollision(*pos, tags));
+        }
+        // same condition as in `Checks::check`
+        if let Some(b) = job.breaks.iter().find(|b| {
+            b.start <= job.start || job.end.is_some_and(|end| b.end.unwrap_or(b.start) > end)
+        }) {
+            problems.push(Problem::BreakOutsideJob(
+                *pos,
+                b.start,
+                job.trimmed_breaks().is_some(),
+            ));
+        }
+    }
+
+    // check for open jobs which have been followed by later jobs
+    for (pos, job) in list.iter().filter(|(_, job)| job.is_open()) {
+        if list
+            .iter()
+            .any(|(_, other)| other.is_open() && other.start > job.start)
+        {
+            if let Some(next) = list
+                .iter()
+                .map(|(_, other)| other.start)
+                .filter(|start| *start > job.start)
+                .min()
+            {
+                problems.push(Problem::OpenJobs(*pos, next));
+            }
+        }
+    }
+
+    // check for overlapping (leave out implausible jobs and pairs of open jobs)
+    let plausible: Vec<&(usize, &Job)> = list
+        .iter()
+        .filter(|(_, job)| job.end.is_none_or(|end| job.start < end))
+        .collect();
+    for (n, (pos, job)) in plausible.iter().enumerate() {
+        for (other_pos, other) in plausible.iter().skip(n + 1) {
+            if matches!(job.overlaps(other, context), Ok(true)) {
+                let duplicate = job.start == other.start
+                    && job.end == other.end
+                    && job.message == other.message
+                    && job.tags == other.tags;
+                problems.push(Problem::Overlaps(*pos, *other_pos, duplicate));
+            }
+        }
+    }
+    problems
+}
+
+#[test]
+fn test_diagnose() {
+    let context = Context::new_test("2023-2-1 18:00");
+    let job = |start: &str, end: Option<&str>, message: &str, tags: &str| {
+        Job::new(
+            start.into(),
+            end.map(|end| end.into()),
+            Some(message.into()),
+            Some(TagSet::from(tags)),
+        )
+        .unwrap()
+    };
+    let mut jobs = Jobs::new();
+    // open job followed by a later one
+    jobs._push(job("2023-2-1 8:00", None, "a", "x"));
+    jobs._push(job("2023-2-1 9:00", Some("2023-2-1 10:00"), "b", "x"));
+    // duplicate
+    jobs._push(job("2023-2-1 9:00", Some("2023-2-1 10:00"), "b", "x"));
+    // end before start
+    let mut broken = job("2023-2-1 11:00", Some("2023-2-1 12:00"), "c", "x");
+    broken.end = Some("2023-2-1 10:30".into());
+    jobs._push(broken);
+    // tag collision
+    jobs.configuration
+        .tags
+        .insert("x".into(), Properties::default());
+    jobs.configuration
+        .tags
+        .insert("y".into(), Properties::default());
+    jobs._push(job("2023-2-1 13:00", None, "d", "x,y"));
+    // break beyond the end and one before the start of a job
+    let mut trimmable = job("2023-1-31 8:00", Some("2023-1-31 9:00"), "e", "x");
+    trimmable.breaks = vec![
+        Break {
+            start: "2023-1-31 7:00".into(),
+            end: Some("2023-1-31 7:30".into()),
+        },
+        Break {
+            start: "2023-1-31 8:30".into(),
+            end: Some("2023-1-31 9:30".into()),
+        },
+    ];
+    jobs._push(trimmable);
+    let mut ambiguous = job("2023-1-31 10:00", Some("2023-1-31 11:00"), "f", "x");
+    ambiguous.breaks = vec![Break {
+        start: "2023-1-31 9:30".into(),
+        end: Some("2023-1-31 10:30".into()),
+    }];
+    jobs._push(ambiguous);
+
+    let problems = diagnose(&jobs, &context);
+    assert_eq!(
+        problems,
+        [
+            Problem::EndBeforeStart(3, "2023-2-1 11:00".into(), "2023-2-1 10:30".into()),
+            Problem::TagCollision(4, TagSet::from("x,y")),
+            Problem::BreakOutsideJob(5, "2023-1-31 7:00".into(), true),
+            Problem::BreakOutsideJob(6, "2023-1-31 9:30".into(), false),
+            Problem::OpenJobs(0, "2023-2-1 9:00".into()),
+            Problem::Overlaps(0, 1, false),
+            Problem::Overlaps(0, 2, false),
+            Problem::Overlaps(1, 2, true),
+        ]
+    );
+    assert_eq!(problems[1].fix(), None);
+    assert_eq!(problems[3].fix(), None);
+
+    // apply fixes
+    for problem in &problems {
+        if let Some(fix) = problem.fix() {
+            jobs.process(
+                &mut std::io::sink(),
+                &fix.command(),
+                Checks::omit(),
+                &context,
+            )
+            .unwrap();
+        }
+    }
+    assert_eq!(jobs[0].end, Some("2023-2-1 9:00".into()));
+    assert!(jobs[2].is_deleted());
+    assert_eq!(
+        jobs[5].breaks,
+        [Break {
+            start: "2023-1-31 8:30".into(),
+            end: Some("2023-1-31 9:00".into()),
+        }]
+    );
+    assert_eq!(
+        diagnose(&jobs, &context),
+        [
+            Problem::EndBeforeStart(3, "2023-2-1 11:00".into(), "2023-2-1 10:30".into()),
+            Problem::TagCollision(4, TagSet::from("x,y")),
+            Problem::BreakOutsideJob(6, "2023-1-31 9:30".into(), false),
+        ]
+    );
+}
@@ -20,6 +20,13 @@ impl Duration {
             minutes: 0,
         }
     }
+    /// Create duration with the given amount of minutes.
+    pub fn minutes(minutes: i64) -> Self {
+        Self::HM {
+            hours: minutes / 60,
+            minutes: minutes % 60,
+        }
+    }
     /// Parse duration from a string.
     pub fn parse(duration: String) -> Result<Self, Error> {
         match Self::parse_hm(&duration)
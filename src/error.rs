@@ -26,13 +26,25 @@ pub enum Error {
     Json(serde_json::Error),
     /// There still is an open job.
     #[error("There still is an open job:\n\n    Pos: {0}\n{1}")]
-    OpenJob(usize, Job),
+    OpenJob(usize, Box<Job>),
+    /// Two open jobs can not be checked for overlapping.
+    #[error("Can not check if two open jobs overlap")]
+    BothOpen,
     /// There is no open job.
     #[error("There is no open job")]
     NoOpenJob,
+    /// The open job is already paused.
+    #[error("The open job is already paused since {0}")]
+    AlreadyPaused(DateTime),
+    /// The open job is not paused.
+    #[error("The open job is not paused")]
+    NotPaused,
     /// End of the job is before it's start
     #[error("End {0} of the job is before it's start {1}")]
     EndBeforeStart(DateTime, DateTime),
+    /// A break does not lie within it's job
+    #[error("Break at {0} does not lie within the job")]
+    BreakOutsideJob(DateTime),
     /// Found warming(s).
     #[error("Found warming(s).")]
     Warnings(Vec<Warning>),
@@ -57,6 +69,21 @@ pub enum Error {
     /// No job found at position {0}
     #[error("No job found at position {0}")]
     JobNotFound(usize),
+    /// Jobs to merge overlap or are open
+    #[error("Can not merge because job at {1} does not follow job ending at {0}")]
+    NotAdjacent(DateTime, DateTime),
+    /// Too few jobs to merge
+    #[error("At least two jobs are needed to merge but found {0}")]
+    MergeTooFew(usize),
+    /// Start, end or duration can not be set for multiple jobs
+    #[error("Start, end or duration can only be changed for a single job (use --shift instead)")]
+    EditManyTimes,
+    /// No job found to use as template
+    #[error("No job found which matches '{0}'")]
+    TemplateNotFound(String),
+    /// User needs to pick a template job
+    #[error("User needs to pick a job to continue")]
+    PickTemplate,
     /// A value is required for '--tags <TAGS>' but none was supplied
     #[error("a value is required for '--tags <TAGS>' but none was supplied")]
     MissingTags,
@@ -72,6 +99,93 @@ pub enum Error {
     /// Parsing of a partial date and time failed
     #[error("Parsing of partial date and time '{0}' failed")]
     PartialDateTimeFormat(String),
+    /// Parsing of a date order failed
+    #[error("Parsing of date order '{0}' failed (use mdy, dmy or ymd)")]
+    DateOrderFormat(String),
+    /// Parsing of a clock failed
+    #[error("Parsing of clock '{0}' failed (use 12 or 24)")]
+    ClockFormat(String),
+    /// Parsing of an export format failed
+    #[error("Parsing of export format '{0}' failed (use something like '%Y-%m-%d %H:%M')")]
+    ExportFormat(String),
+    /// Parsing of a time zone failed
+    #[error("Parsing of time zone '{0}' failed (use local or an IANA name like Europe/Berlin)")]
+    TimeZoneFormat(String),
+    /// Parsing of working hours failed
+    #[error("Parsing of working hours '{0}' failed (use something like 8:00-17:00)")]
+    WorkHoursFormat(String),
+    /// Parsing of day boundary failed
+    #[error("Parsing of day boundary '{0}' failed (use something like 4:00)")]
+    DayBoundaryFormat(String),
+    /// Unknown name of a check
+    #[error("Unknown check '{0}' (use overlaps, unknown-tags, confirm-deletion, confirm-modification, locked or max-hours)")]
+    UnknownCheck(String),
+    /// Parsing of a policy failed
+    #[error("Parsing of policy '{0}' failed (use fail, warn or accept)")]
+    PolicyFormat(String),
+    /// Unknown shell to generate completion for
+    #[error("Unknown shell '{0}' (use bash, elvish, fish, powershell or zsh)")]
+    UnknownShell(String),
+    /// Unknown name of a profile
+    #[error("Unknown profile '{0}' (add it to the profiles within the configuration file)")]
+    UnknownProfile(String),
+    /// Profiles can only be reported
+    #[error("Option --profiles can only be used to report jobs (with --report only)")]
+    ProfilesWithoutReport,
+    /// The open job seems to be forgotten
+    #[error("Open job at position {} seems to be forgotten", .0 + 1)]
+    ForgottenJob(usize),
+    /// Changing invoiced jobs is not allowed
+    #[error("Jobs of invoice '{0}' are locked (use --unlock to change them anyway)")]
+    Locked(String),
+    /// Open jobs can not be invoiced
+    #[error("Open job at position {} can not be invoiced", .0 + 1)]
+    InvoiceOpenJob(usize),
+    /// Parsing of a rounding failed
+    #[error("Parsing of rounding '{0}' failed (use up, nearest or down)")]
+    RoundingFormat(String),
+    /// Parsing of a rounding level failed
+    #[error("Parsing of rounding level '{0}' failed (use job, day or invoice)")]
+    RoundingLevelFormat(String),
+}
+
+impl Error {
+    /// Exit code of the application which distinguishes classes of errors
+    /// (invalid arguments use 2 like argument parsing does).
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Warnings(_) | Error::Cancel => 3,
+            Error::EnterMessage | Error::PickTemplate => 4,
+            Error::Locked(_) => 5,
+            Error::ForgottenJob(_) => 6,
+            Error::NoDatabase
+            | Error::Confy(_)
+            | Error::Io(_)
+            | Error::Fmt(_)
+            | Error::Json(_)
+            | Error::OutputFileExists(_) => 7,
+            Error::DateTimeParse(_)
+            | Error::MissingTags
+            | Error::RangeFormat(_)
+            | Error::DurationFormat(_)
+            | Error::PartialDateTimeFormat(_)
+            | Error::DateOrderFormat(_)
+            | Error::ClockFormat(_)
+            | Error::ExportFormat(_)
+            | Error::TimeZoneFormat(_)
+            | Error::WorkHoursFormat(_)
+            | Error::DayBoundaryFormat(_)
+            | Error::RoundingFormat(_)
+            | Error::RoundingLevelFormat(_)
+            | Error::UnknownColumn(_)
+            | Error::UnknownShell(_)
+            | Error::UnknownProfile(_)
+            | Error::ProfilesWithoutReport
+            | Error::UnknownCheck(_)
+            | Error::PolicyFormat(_) => 2,
+            _ => 1,
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -88,6 +202,7 @@ impl From<std::fmt::Error> for Error {
 
 /// Warnings that occur in *jobber* which the user might want to ignore.
 #[derive(Error, Debug)]
+#[allow(clippy::large_enum_variant)]
 pub enum Warning {
     /// The job you want to add overlaps existing one(s)
     #[error("The job you want to add overlaps existing one(s):\n\nJob you want to add:\n\n{new}\nExisting overlapping jobs:\n\n{existing}")]
@@ -99,6 +214,33 @@ pub enum Warning {
     /// You are about to delete job(s) at the following position(s).
     #[error("You are about to delete job(s) at the following position(s): {0}")]
     ConfirmDeletion(Positions),
+    /// You are about to modify the following job(s).
+    #[error("You are about to modify the following job(s):\n{}", format::diffs(.0))]
+    ConfirmModification(Vec<(usize, Job, Job)>),
+    /// The work time of a day would exceed the maximum hours.
+    #[error(
+        "The work time at {date}{} would sum up to {hours:.2} hours which exceeds the maximum of {max_hours} hours.",
+        tag.as_ref().map(|tag| format!(" for tag '{tag}'")).unwrap_or_default()
+    )]
+    MaxHours {
+        date: Date,
+        tag: Option<String>,
+        hours: f64,
+        max_hours: u32,
+    },
+}
+
+impl Warning {
+    /// Get the check which emits this warning.
+    pub fn check(&self) -> Check {
+        match self {
+            Warning::Overlaps { .. } => Check::Overlaps,
+            Warning::UnknownTags(_) => Check::UnknownTags,
+            Warning::ConfirmDeletion(_) => Check::ConfirmDeletion,
+            Warning::ConfirmModification(_) => Check::ConfirmModification,
+            Warning::MaxHours { .. } => Check::MaxHours,
+        }
+    }
 }
 
 /// List of jobs with index extracted from database list.
@@ -110,6 +252,8 @@ pub struct JobListOwned {
     jobs: Vec<(usize, Job)>,
     /// Copy of the configuration of the original [Jobs] database.
     pub configuration: Configuration,
+    /// Temporal context until which open jobs count when displayed.
+    pub context: Context,
 }
 
 impl JobListOwned {
@@ -123,6 +267,7 @@ impl From<JobList<'_>> for JobListOwned {
     fn from(list: JobList) -> Self {
         Self {
             configuration: list.configuration.clone(),
+            context: list.context.clone(),
             jobs: list.into_iter().map(|(n, j)| (n, j.clone())).collect(),
         }
     }
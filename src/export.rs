@@ -11,14 +11,36 @@ pub enum Column {
     End,
     Duration,
     Hours,
+    RoundedHours,
+    RawHours,
     Message,
     Tags,
     Pay,
+    Billable,
+    Invoice,
     Rate,
     MaxHours,
     Resolution,
 }
 
+/// Names of all columns which can be exported.
+pub const COLUMNS: &[&str] = &[
+    "pos",
+    "start",
+    "end",
+    "duration",
+    "hours",
+    "rounded-hours",
+    "raw-hours",
+    "message",
+    "tags",
+    "pay",
+    "billable",
+    "invoice",
+    "rate",
+    "resolution",
+];
+
 impl Column {
     // Create column from String.
     pub fn from(column: &str) -> Result<Self, Error> {
@@ -28,9 +50,13 @@ impl Column {
             "e" | "end" => Column::End,
             "d" | "duration" => Column::Duration,
             "h" | "hours" => Column::Hours,
+            "rounded" | "rounded-hours" => Column::RoundedHours,
+            "raw" | "raw-hours" => Column::RawHours,
             "m" | "message" => Column::Message,
             "t" | "tags" => Column::Tags,
             "p" | "pay" => Column::Pay,
+            "b" | "billable" => Column::Billable,
+            "i" | "invoice" => Column::Invoice,
             "rate" => Column::Rate,
             "resolution" => Column::Resolution,
             _ => return Err(Error::UnknownColumn(column.to_string())),
@@ -49,9 +75,13 @@ impl std::fmt::Display for Column {
                 Column::End => "End",
                 Column::Duration => "Duration",
                 Column::Hours => "Hours",
+                Column::RoundedHours => "Rounded.Hours",
+                Column::RawHours => "Raw.Hours",
                 Column::Message => "Message",
                 Column::Tags => "Tags",
                 Column::Pay => "Pay",
+                Column::Billable => "Billable",
+                Column::Invoice => "Invoice",
                 Column::Rate => "Rate",
                 Column::MaxHours => "Max.Hours",
                 Column::Resolution => "Resolution",
@@ -95,6 +125,8 @@ pub fn export_csv<W: std::io::Write>(
     columns: &Columns,
     context: &Context,
 ) -> Result<(), Error> {
+    // export with the settings of the database these jobs stem from
+    locale::init(&jobs.configuration.settings);
     let title = columns
         .0
         .iter()
@@ -102,6 +134,9 @@ pub fn export_csv<W: std::io::Write>(
         .collect::<Vec<String>>()
         .join(",");
     writeln!(w, "{}", title)?;
+    let format = locale::export_format();
+    check_export_format(&format)?;
+    let rounded = jobs.rounded_hours_per_job(context);
     for (pos, job) in jobs.iter().sorted_by(|l, r| l.1.cmp(r.1)) {
         for (c, column) in columns.iter().enumerate() {
             if c > 0 {
@@ -110,25 +145,17 @@ pub fn export_csv<W: std::io::Write>(
             let properties = jobs.configuration.get_checked(&job.tags)?;
             match column {
                 Column::Pos => write!(w, "{}", pos + 1)?,
-                Column::Start => write!(w, r#""{}""#, job.start.format("%m/%d/%Y %H:%M"))?,
+                Column::Start => write!(w, r#""{}""#, job.start.format(&format))?,
                 Column::End => write!(
                     w,
                     r#""{}""#,
                     if let Some(end) = job.end {
-                        end.format("%m/%d/%Y %H:%M")
-                    } else {
-                        context.time().format("%m/%d/%Y %H:%M")
-                    }
-                )?,
-                Column::Duration => write!(
-                    w,
-                    r#""{}""#,
-                    if let Some(end) = job.end {
-                        &end - &job.start
+                        end.format(&format)
                     } else {
-                        &context.time() - &job.start
+                        context.time().format(&format)
                     }
                 )?,
+                Column::Duration => write!(w, r#""{}""#, job.duration(context))?,
                 Column::Message => write!(
                     w,
                     r#""{}""#,
@@ -138,11 +165,33 @@ pub fn export_csv<W: std::io::Write>(
                         "\"\""
                     )
                 )?,
-                Column::Hours => write!(w, "{}", job.hours(properties))?,
+                Column::Hours => write!(w, "{}", job.hours(properties, context))?,
+                Column::RoundedHours => write!(w, "{}", rounded[pos])?,
+                Column::RawHours => {
+                    write!(w, "{}", (job.raw_hours(context) / 0.01).round() * 0.01)?
+                }
                 Column::Tags => write!(w, r#""{}""#, job.tags.0.join(","))?,
                 Column::Pay => {
                     if let Some(rate) = properties.rate {
-                        write!(w, "{}", job.hours(properties) * rate)?;
+                        if job.is_billable(properties) {
+                            write!(w, "{}", job.hours(properties, context) * rate)?;
+                        } else {
+                            write!(w, "0")?;
+                        }
+                    }
+                }
+                Column::Billable => write!(
+                    w,
+                    r#""{}""#,
+                    if job.is_billable(properties) {
+                        "yes"
+                    } else {
+                        "no"
+                    }
+                )?,
+                Column::Invoice => {
+                    if let Some(invoice) = &job.invoice {
+                        write!(w, r#""{}""#, str::replace(invoice, "\"", "\"\""))?;
                     }
                 }
                 Column::Rate => {
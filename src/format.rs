@@ -14,6 +14,53 @@ pub fn end(end: &DateTime) -> String {
     format!("{}{}{}", Fg(Magenta), end, Fg(Reset))
 }
 
+/// Format time at the time zone where the job was started if it differs from the reporting time zone.
+pub fn origin(time: &DateTime, offset: Option<i32>) -> String {
+    if let Some(offset) = offset {
+        if offset != time.offset() {
+            return format!(
+                " ({} {})",
+                time.into_offset(offset)
+                    .format(locale::clock().time_format()),
+                format_offset(offset)
+            );
+        }
+    }
+    String::new()
+}
+
+/// Format differences between original and modified jobs (including positions) in a string.
+pub fn diffs(diffs: &[(usize, Job, Job)]) -> String {
+    let mut result = String::new();
+    for (pos, old, new) in diffs {
+        result += &format!("\n    Pos: {}\n", pos + 1);
+        let mut diff = |title: &str, old: String, new: String| {
+            if old != new {
+                result += &format!(
+                    "{}-{title}: {old}{}\n{}+{title}: {new}{}\n",
+                    Fg(Red),
+                    Fg(Reset),
+                    Fg(Green),
+                    Fg(Reset)
+                );
+            }
+        };
+        diff("  Start", old.start.to_string(), new.start.to_string());
+        diff(
+            "    End",
+            old.end.map(|end| end.to_string()).unwrap_or_default(),
+            new.end.map(|end| end.to_string()).unwrap_or_default(),
+        );
+        diff(
+            "Message",
+            old.message.clone().unwrap_or_default(),
+            new.message.clone().unwrap_or_default(),
+        );
+        diff("   Tags", old.tags.to_string(), new.tags.to_string());
+    }
+    result
+}
+
 /// return colored hours bar in a string
 pub fn hours_bar(hours: f64, properties: &Properties) -> String {
     fn bar(hours: f64) -> String {
@@ -0,0 +1,126 @@
+//! Untracked time between consecutive jobs within working hours.
+
+use super::prelude::*;
+use std::collections::BTreeSet;
+
+/// Minimum length of a gap in minutes if not configured.
+const MIN_GAP: i64 = 15;
+
+/// Untracked time within the working hours of a day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gap {
+    /// Begin of the untracked time
+    pub start: DateTime,
+    /// End of the untracked time
+    pub end: DateTime,
+    /// Position of the job before the gap (if there is one at that day)
+    pub before: Option<usize>,
+    /// Position of the job after the gap (if there is one at that day)
+    pub after: Option<usize>,
+}
+
+impl Gap {
+    /// Length of the gap in minutes.
+    pub fn minutes(&self) -> i64 {
+        (&self.end - &self.start).num_minutes()
+    }
+}
+
+impl std::fmt::Display for Gap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} - {} ({} hours)",
+            format::start(&self.start),
+            format::end(&self.end),
+            format::hours_pure(self.minutes() as f64 / 60.0),
+        )?;
+        match (self.before, self.after) {
+            (Some(before), Some(after)) => {
+                write!(f, " between Pos {} and Pos {}", before + 1, after + 1)
+            }
+            (Some(before), None) => write!(f, " after Pos {}", before + 1),
+            (None, Some(after)) => write!(f, " before Pos {}", after + 1),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Find gaps within the working hours of all work days at which any job of the given list lies.
+///
+/// Gaps are searched between all jobs of the database (not only the listed ones) as well as
+/// between the begin of the working hours and the first job and between the last job and the end
+/// of the working hours. Only gaps which are at least as long as the configured minimum are
+/// returned.
+pub fn gaps(jobs: &Jobs, list: &JobList, context: &Context) -> Vec<Gap> {
+    let settings = &list.configuration.settings;
+    let work_hours = settings.work_hours.unwrap_or_default();
+    let min_gap = settings.min_gap.unwrap_or(MIN_GAP);
+
+    // work days to search in
+    let days: BTreeSet<Date> = list
+        .iter()
+        .flat_map(|(_, job)| [job.start.date(), job.end.unwrap_or(context.time()).date()])
+        .collect();
+
+    // sort all jobs by start time and use current time as end of an open job
+    let mut all: Vec<(usize, DateTime, DateTime)> = jobs
+        .iter()
+        .enumerate()
+        .filter(|(_, job)| !job.is_deleted())
+        .map(|(pos, job)| (pos, job.start, job.end.unwrap_or(context.time())))
+        .collect();
+    all.sort_by_key(|(_, start, _)| *start);
+
+    let mut gaps = Vec::new();
+    for day in days {
+        // working hours of that day but not beyond now
+        let start = day.at(work_hours.start);
+        let end = day.at(work_hours.end).min(context.time());
+        if start >= end {
+            continue;
+        }
+        let mut push = |gap: Gap| {
+            if gap.minutes() >= min_gap {
+                gaps.push(gap);
+            }
+        };
+        // end of tracked time so far and the jobs before and after the current gap
+        let mut cursor = start;
+        let mut before = None;
+        let mut after = None;
+        for (pos, job_start, job_end) in &all {
+            if *job_start >= end {
+                // job after working hours
+                if job_start.date() == day {
+                    after = Some(*pos);
+                }
+                break;
+            }
+            if *job_start > cursor {
+                push(Gap {
+                    start: cursor,
+                    end: *job_start,
+                    before,
+                    after: Some(*pos),
+                });
+            }
+            if *job_end > cursor {
+                cursor = *job_end;
+                before = Some(*pos);
+            } else if cursor == start && job_end.date() == day {
+                // job before working hours
+                before = Some(*pos);
+            }
+        }
+        if cursor < end {
+            push(Gap {
+                start: cursor,
+                end,
+                before,
+                after,
+            });
+        }
+    }
+    gaps
+}
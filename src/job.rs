@@ -9,6 +9,15 @@ fn none<T>() -> Option<T> {
     Option::None
 }
 
+/// A break within a job.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Break {
+    /// Starting time
+    pub start: DateTime,
+    /// Ending time or None if work was not resumed yet
+    pub end: Option<DateTime>,
+}
+
 /// One portion of work
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Job {
@@ -23,6 +32,18 @@ pub struct Job {
     /// Deletion Mark
     #[serde(default = "none")]
     deleted: Option<DateTime>,
+    /// UTC offset (in seconds) of the system's time zone where the job was started
+    #[serde(default = "none", skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    /// Breaks within the job
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub breaks: Vec<Break>,
+    /// Overwrites if the job is billable (otherwise the tag's configuration decides)
+    #[serde(default = "none", skip_serializing_if = "Option::is_none")]
+    pub billable: Option<bool>,
+    /// Reference of the invoice which includes this job
+    #[serde(default = "none", skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<String>,
 }
 
 impl Job {
@@ -42,12 +63,12 @@ impl Job {
             start,
             end,
             message,
-            tags: if let Some(tags) = tags {
-                tags
-            } else {
-                TagSet::new()
-            },
+            tags: tags.unwrap_or_default(),
             deleted: None,
+            offset: Some(start.system_offset()),
+            breaks: Vec::new(),
+            billable: None,
+            invoice: None,
         })
     }
     /// Return `true` if latest job has no ending.
@@ -58,40 +79,249 @@ impl Job {
     pub fn delete(&mut self, context: &Context) {
         self.deleted = Some(context.time());
     }
+    /// Restore this job (by removing the deletion mark)
+    pub fn undelete(&mut self) {
+        self.deleted = None;
+    }
     /// Return `true` if job has been deleted
     pub fn is_deleted(&self) -> bool {
         self.deleted.is_some()
     }
-    /// Get minutes worked without rounding to resolution.
-    fn minutes(&self) -> i64 {
+    /// Return `true` if job is open and currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.is_open() && self.breaks.last().is_some_and(|b| b.end.is_none())
+    }
+    /// Begin a break at the given time.
+    pub fn pause(&mut self, time: DateTime) -> Result<(), Error> {
+        if !self.is_open() {
+            return Err(Error::NoOpenJob);
+        }
+        if let Some(last) = self.breaks.last() {
+            if let Some(end) = last.end {
+                if time < end {
+                    return Err(Error::EndBeforeStart(end, time));
+                }
+            } else {
+                return Err(Error::AlreadyPaused(last.start));
+            }
+        }
+        if time <= self.start {
+            return Err(Error::EndBeforeStart(self.start, time));
+        }
+        self.breaks.push(Break {
+            start: time,
+            end: None,
+        });
+        Ok(())
+    }
+    /// End the current break at the given time.
+    pub fn resume(&mut self, time: DateTime) -> Result<(), Error> {
+        if !self.is_paused() {
+            return Err(Error::NotPaused);
+        }
+        let last = self.breaks.last_mut().unwrap();
+        if time <= last.start {
+            return Err(Error::EndBeforeStart(last.start, time));
+        }
+        last.end = Some(time);
+        Ok(())
+    }
+    /// Get the breaks of this job without those which lie completely outside of it and with
+    /// those which last beyond it's end cut there.
+    ///
+    /// Returns `None` if any break begins before (or with) the job but ends within it because
+    /// then it is unclear if the break or the job's start is wrong.
+    pub fn trimmed_breaks(&self) -> Option<Vec<Break>> {
+        let mut breaks = Vec::new();
+        for b in &self.breaks {
+            if b.end.is_some_and(|end| end <= self.start)
+                || self.end.is_some_and(|end| b.start >= end)
+            {
+                continue;
+            }
+            if b.start <= self.start {
+                return None;
+            }
+            breaks.push(Break {
+                start: b.start,
+                end: match (b.end, self.end) {
+                    (Some(b_end), Some(end)) => Some(b_end.min(end)),
+                    (b_end, _) => b_end,
+                },
+            });
+        }
+        Some(breaks)
+    }
+    /// Finish the job at the given time (and an open break with it).
+    pub fn finish(&mut self, end: DateTime) -> Result<(), Error> {
+        if let Some(last) = self.breaks.last_mut() {
+            if end <= last.start {
+                return Err(Error::EndBeforeStart(last.start, end));
+            }
+            if last.end.is_none() {
+                last.end = Some(end);
+            }
+        }
+        self.end = Some(end);
+        Ok(())
+    }
+    /// Shift start, end and breaks by the given duration.
+    pub fn shift(&mut self, duration: chrono::Duration) {
+        self.start = self.start + duration;
+        self.end = self.end.map(|end| end + duration);
+        for b in &mut self.breaks {
+            b.start = b.start + duration;
+            b.end = b.end.map(|end| end + duration);
+        }
+    }
+    /// Cut job at the given time into two jobs (breaks will be cut too).
+    pub fn cut(&self, at: DateTime) -> Result<(Job, Job), Error> {
+        if at <= self.start {
+            return Err(Error::EndBeforeStart(self.start, at));
+        }
+        if let Some(end) = self.end {
+            if at >= end {
+                return Err(Error::EndBeforeStart(at, end));
+            }
+        }
+        let mut first = self.clone();
+        first.end = Some(at);
+        first.breaks = Vec::new();
+        let mut second = self.clone();
+        second.start = at;
+        second.breaks = Vec::new();
+        for b in &self.breaks {
+            if b.start < at {
+                first.breaks.push(Break {
+                    start: b.start,
+                    end: Some(b.end.map_or(at, |end| end.min(at))),
+                });
+            }
+            if b.end.is_none_or(|end| end > at) {
+                second.breaks.push(Break {
+                    start: b.start.max(at),
+                    end: b.end,
+                });
+            }
+        }
+        first.breaks.retain(|b| Some(b.start) != b.end);
+        second.breaks.retain(|b| Some(b.start) != b.end);
+        Ok((first, second))
+    }
+    /// Join a following job into this one.
+    /// A gap between both jobs becomes a break and messages and tags will be combined.
+    /// The caller has to make sure that no other job lies within that gap.
+    pub fn join(&mut self, other: &Job) -> Result<(), Error> {
         let end = if let Some(end) = self.end {
             end
         } else {
-            DateTime::now()
+            return Err(Error::NotAdjacent(self.start, other.start));
+        };
+        if other.start < end {
+            return Err(Error::NotAdjacent(end, other.start));
+        }
+        if other.start > end {
+            self.breaks.push(Break {
+                start: end,
+                end: Some(other.start),
+            });
+        }
+        self.breaks.extend(other.breaks.iter().copied());
+        self.end = other.end;
+        self.message = match (&self.message, &other.message) {
+            (Some(message), Some(other)) if message != other => Some(format!("{message}\n{other}")),
+            (None, message) | (message, _) => message.clone(),
         };
-        (&end - &self.start).num_minutes()
+        self.tags.insert_many(other.tags.clone());
+        Ok(())
     }
-    /// Get hours worked considering resolution.
-    pub fn hours(&self, properties: &Properties) -> f64 {
-        if let Some(resolution) = properties.resolution {
-            (self.minutes() as f64 / 60.0 / resolution).ceil() * resolution
+    /// Get the time intervals (start and end) in which was worked.
+    fn working(&self, context: &Context) -> Vec<(DateTime, DateTime)> {
+        let end = if let Some(end) = self.end {
+            end
         } else {
-            (self.minutes() as f64 / 60.0 / 0.01).round() * 0.01
+            context.time()
+        };
+        let mut result = Vec::new();
+        let mut start = self.start;
+        for b in &self.breaks {
+            let b_end = b.end.unwrap_or(end).min(end);
+            if b.start > start {
+                result.push((start, b.start.min(end)));
+            }
+            start = start.max(b_end);
+        }
+        if end > start {
+            result.push((start, end));
         }
+        result
     }
-    /// Return `true` if the given job overlaps another job in the database in time.
-    pub fn overlaps(&self, other: &Job, context: &Context) -> bool {
-        if let Some(self_end) = self.end {
-            if let Some(other_end) = other.end {
-                self.start < other_end && self_end > other.start
-            } else {
-                self_end < other.start
-            }
-        } else if let Some(other_end) = other.end {
-            self.start < other_end && context.time() > other.start
+    /// Get minutes worked without rounding to resolution.
+    fn minutes(&self, context: &Context) -> i64 {
+        self.working(context)
+            .iter()
+            .map(|(start, end)| (end - start).num_minutes())
+            .sum()
+    }
+    /// Get duration worked without rounding to resolution.
+    pub fn duration(&self, context: &Context) -> Duration {
+        Duration::minutes(
+            self.working(context)
+                .iter()
+                .map(|(start, end)| (end - start).num_minutes())
+                .sum(),
+        )
+    }
+    /// Get hours worked considering resolution and minimum billable hours.
+    ///
+    /// Hours are only rounded to the resolution if they shall be rounded per job.
+    /// Open jobs count until the time of the given context.
+    pub fn hours(&self, properties: &Properties, context: &Context) -> f64 {
+        Self::billed_hours(self.raw_hours(context), properties)
+    }
+    /// Apply resolution (if hours are rounded per job) and minimum billable hours to the given hours.
+    fn billed_hours(hours: f64, properties: &Properties) -> f64 {
+        let hours = if properties.rounding_level() == RoundingLevel::Job {
+            properties.round(hours)
         } else {
-            panic!("checking intersection of two open jobs: {} {}", self, other)
+            (hours / 0.01).round() * 0.01
+        };
+        if let Some(min_hours) = properties.min_hours {
+            hours.max(min_hours)
+        } else {
+            hours
+        }
+    }
+    /// Return `true` if this job has been invoiced.
+    pub fn is_invoiced(&self) -> bool {
+        self.invoice.is_some()
+    }
+    /// Return `true` if the job is billable (by it's own flag or by the given properties).
+    pub fn is_billable(&self, properties: &Properties) -> bool {
+        self.billable.or(properties.billable).unwrap_or(true)
+    }
+    /// Get hours worked without rounding to resolution.
+    pub fn raw_hours(&self, context: &Context) -> f64 {
+        self.minutes(context) as f64 / 60.0
+    }
+    /// Get hours worked considering minimum billable hours but without rounding to resolution.
+    pub fn unrounded_hours(&self, properties: &Properties, context: &Context) -> f64 {
+        self.raw_hours(context)
+            .max(properties.min_hours.unwrap_or(0.0))
+    }
+    /// Return `true` if the given job overlaps another job in the database in time.
+    /// Breaks within the jobs are not considered to be overlapping.
+    /// Two open jobs can not be checked and lead to `Error::BothOpen`.
+    pub fn overlaps(&self, other: &Job, context: &Context) -> Result<bool, Error> {
+        if self.end.is_none() && other.end.is_none() {
+            return Err(Error::BothOpen);
         }
+        let others = other.working(context);
+        Ok(self.working(context).iter().any(|(start, end)| {
+            others
+                .iter()
+                .any(|(other_start, other_end)| start < other_end && end > other_start)
+        }))
     }
     /// Get start time as local time.
     fn start_local(&self) -> NaiveDateTime {
@@ -105,60 +335,154 @@ impl Job {
             context.time().into_local()
         }
     }
-    /// Split job into multiple so that the resulting jobs do not pass over midnight.
+    /// Split hours of this job into work days (by the start of each part and to hundredths).
+    ///
+    /// Minimum billable hours (and rounding if the hours are rounded per job) are applied to the
+    /// whole job and what this adds is attributed to the first day.
+    /// Open jobs count until the time of the given context.
+    pub fn hours_per_day(
+        &self,
+        properties: &Properties,
+        context: &Context,
+    ) -> Vec<(DateTime, f64)> {
+        let mut result: Vec<(DateTime, f64)> = self
+            .split(context)
+            .iter()
+            .map(|part| (part.start, (part.raw_hours(context) / 0.01).round() * 0.01))
+            .collect();
+        let raw_hours = self.duration(context).num_minutes() as f64 / 60.0;
+        let hours = if properties.rounding_level() == RoundingLevel::Job {
+            Self::billed_hours(raw_hours, properties)
+        } else {
+            raw_hours.max(properties.min_hours.unwrap_or(0.0))
+        };
+        let rest = hours - result.iter().map(|(_, hours)| hours).sum::<f64>();
+        if let Some((_, first)) = result.first_mut() {
+            *first = ((*first + rest) / 0.01).round() * 0.01;
+        }
+        result
+    }
+    /// Split job into multiple so that the resulting jobs do not pass over the day boundary
+    /// (midnight by default).
     pub fn split(&self, context: &Context) -> Vec<Job> {
+        self.split_by(locale::day_boundary(), context)
+    }
+    /// Split job into multiple so that the resulting jobs do not pass over the given day
+    /// boundary (offset to midnight).
+    pub fn split_by(&self, boundary: chrono::Duration, context: &Context) -> Vec<Job> {
         let mut result = Vec::new();
         let mut start = self.start_local();
         let end = self.end_local(context);
 
         loop {
-            let e = start
+            let e = (start - boundary)
                 .date()
                 .checked_add_days(Days::new(1))
                 .unwrap()
-                .and_time(NaiveTime::from_num_seconds_from_midnight_opt(0, 0).unwrap());
+                .and_time(NaiveTime::from_num_seconds_from_midnight_opt(0, 0).unwrap())
+                + boundary;
             if e > end {
-                result.push(Job {
-                    start: DateTime::from_local(&start),
-                    end: Some(DateTime::from_local(&end)),
-                    message: self.message.clone(),
-                    tags: self.tags.clone(),
-                    deleted: None,
-                });
+                result.push(self.part(
+                    DateTime::from_local(&start),
+                    DateTime::from_local(&end),
+                    context,
+                ));
                 break;
             }
 
-            result.push(Job {
-                start: DateTime::from_local(&start),
-                end: Some(DateTime::from_local(&e)),
-                message: self.message.clone(),
-                tags: self.tags.clone(),
-                deleted: None,
-            });
+            result.push(self.part(
+                DateTime::from_local(&start),
+                DateTime::from_local(&e),
+                context,
+            ));
             start = e;
         }
         result
     }
+    /// Get a finished part of this job between `start` and `end` which includes the breaks within.
+    fn part(&self, start: DateTime, end: DateTime, context: &Context) -> Job {
+        Job {
+            start,
+            end: Some(end),
+            message: self.message.clone(),
+            tags: self.tags.clone(),
+            deleted: None,
+            offset: self.offset,
+            breaks: self
+                .breaks
+                .iter()
+                .filter_map(|b| {
+                    let b_start = b.start.max(start);
+                    let b_end = b.end.unwrap_or(context.time()).min(end);
+                    if b_start < b_end {
+                        Some(Break {
+                            start: b_start,
+                            end: Some(b_end),
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+            billable: self.billable,
+            invoice: self.invoice.clone(),
+        }
+    }
     /// Print a job in human readable format using colors.
+    /// Open jobs count until the time of the given context.
     pub fn writeln(
         &self,
         f: &mut std::fmt::Formatter<'_>,
         properties: &Properties,
+        context: &Context,
     ) -> std::fmt::Result {
-        writeln!(f, "  Start: {}", format::start(&self.start))?;
+        writeln!(
+            f,
+            "  Start: {}{}",
+            format::start(&self.start),
+            format::origin(&self.start, self.offset)
+        )?;
         if let Some(end) = &self.end {
-            writeln!(f, "    End: {}", format::end(end))?;
+            writeln!(
+                f,
+                "    End: {}{}",
+                format::end(end),
+                format::origin(end, self.offset)
+            )?;
         }
-        let hours = self.hours(properties);
+        for b in &self.breaks {
+            if let Some(end) = &b.end {
+                writeln!(
+                    f,
+                    "  Break: {} - {}",
+                    format::start(&b.start),
+                    format::end(end)
+                )?;
+            } else {
+                writeln!(f, " Paused: {}", format::start(&b.start))?;
+            }
+        }
+        let hours = self.hours(properties, context);
         if hours > 0.0 {
+            // show worked hours besides billed ones if there is a minimum
+            let raw = if properties.min_hours.is_some() {
+                format!(
+                    " (worked {})",
+                    format::hours_pure((self.raw_hours(context) / 0.01).round() * 0.01)
+                )
+            } else {
+                String::new()
+            };
             writeln!(
                 f,
-                "  Hours: {}{}",
+                "  Hours: {}{raw}{}",
                 format::hours(hours, properties),
                 format::hours_bar(hours, properties)
             )?;
         }
-        if properties.rate.is_some() {
+        if !self.is_billable(properties) {
+            writeln!(f, "  Costs: non-billable")?;
+        } else if properties.rate.is_some() {
             writeln!(f, "  Costs: {}", format::pay(hours, properties))?;
         }
         if let Some(message) = &self.message {
@@ -167,19 +491,23 @@ impl Job {
         if !self.tags.is_empty() {
             writeln!(f, "   Tags: {}", self.tags)?;
         }
+        if let Some(invoice) = &self.invoice {
+            writeln!(f, "Invoice: {}", invoice)?;
+        }
         Ok(())
     }
 }
 
+/// Open jobs are displayed with the hours worked until now.
 impl std::fmt::Display for Job {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.writeln(f, &Properties::default())
+        self.writeln(f, &Properties::default(), &Context::now())
     }
 }
 
 impl PartialOrd for Job {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.start.partial_cmp(&other.start)
+        Some(self.cmp(other))
     }
 }
 
@@ -228,6 +556,104 @@ fn test_split() {
     );
 }
 
+/// Test job splitting at a day boundary other than midnight.
+#[test]
+fn test_split_by() {
+    let context = Context::now();
+    let boundary = chrono::Duration::hours(4);
+    let f = "%Y-%m-%d %H:%M";
+
+    // late evening session stays within the starting day
+    let job = Job::new(
+        "2023-1-1 22:00".into(),
+        Some("2023-1-2 2:00".into()),
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(job.split_by(boundary, &context).len(), 1);
+
+    let job = Job::new(
+        "2023-1-1 20:00".into(),
+        Some("2023-1-2 6:00".into()),
+        None,
+        None,
+    )
+    .unwrap();
+    let jobs = job.split_by(boundary, &context);
+    assert_eq!(jobs.len(), 2);
+    assert_eq!(
+        jobs[0].end.unwrap().into_local(),
+        NaiveDateTime::parse_from_str("2023-1-2 04:00", f).unwrap()
+    );
+    assert_eq!(
+        jobs[1].start.into_local(),
+        NaiveDateTime::parse_from_str("2023-1-2 04:00", f).unwrap()
+    );
+}
+
+/// Test job splitting and hours of a job with breaks.
+#[test]
+fn test_breaks() {
+    let context = Context::now();
+    let mut job = Job::new("2023-1-1 20:00".into(), None, None, None).unwrap();
+    job.pause("2023-1-1 23:00".into()).unwrap();
+    job.resume("2023-1-2 1:00".into()).unwrap();
+    job.pause("2023-1-2 2:00".into()).unwrap();
+    job.finish("2023-1-2 3:00".into()).unwrap();
+    assert_eq!(job.breaks.len(), 2);
+    assert_eq!(job.duration(&context).num_minutes(), 4 * 60);
+    assert_eq!(job.hours(&Properties::default(), &context), 4.0);
+
+    let jobs = job.split(&context);
+    assert_eq!(jobs.len(), 2);
+    assert_eq!(jobs[0].hours(&Properties::default(), &context), 3.0);
+    assert_eq!(jobs[0].breaks.len(), 1);
+    assert_eq!(jobs[1].hours(&Properties::default(), &context), 1.0);
+    assert_eq!(jobs[1].breaks.len(), 2);
+}
+
+/// Test cutting and joining jobs.
+#[test]
+fn test_cut_and_join() {
+    let context = Context::now();
+    let mut job = Job::new(
+        "2023-1-1 8:00".into(),
+        None,
+        Some("work".into()),
+        Some("a".into()),
+    )
+    .unwrap();
+    job.pause("2023-1-1 12:00".into()).unwrap();
+    job.resume("2023-1-1 13:00".into()).unwrap();
+    job.finish("2023-1-1 17:00".into()).unwrap();
+
+    assert!(job.cut("2023-1-1 8:00".into()).is_err());
+    assert!(job.cut("2023-1-1 18:00".into()).is_err());
+
+    // cut within break
+    let (first, mut second) = job.cut("2023-1-1 12:30".into()).unwrap();
+    assert_eq!(first.end, Some("2023-1-1 12:30".into()));
+    assert_eq!(first.hours(&Properties::default(), &context), 4.0);
+    assert_eq!(second.start, "2023-1-1 12:30".into());
+    assert_eq!(second.hours(&Properties::default(), &context), 4.0);
+
+    // join both parts again with different message
+    second.message = Some("more work".into());
+    let mut joined = first.clone();
+    joined.join(&second).unwrap();
+    assert_eq!(joined.start, job.start);
+    assert_eq!(joined.end, job.end);
+    assert_eq!(joined.hours(&Properties::default(), &context), 8.0);
+    assert_eq!(joined.message, Some("work\nmore work".into()));
+
+    // can not join jobs which overlap
+    assert!(matches!(
+        second.clone().join(&first),
+        Err(Error::NotAdjacent(_, _))
+    ));
+}
+
 /// Helper for [test_overlaps]
 #[cfg(test)]
 fn test_overlap(
@@ -254,6 +680,7 @@ fn test_overlap(
         .unwrap(),
         &context,
     )
+    .unwrap()
 }
 
 /// Test job overlapping check.
@@ -294,3 +721,18 @@ fn test_overlaps() {
         Some("2023-1-1 12:00")
     ));
 }
+
+/// Test that jobs without an originating UTC offset are stored like before.
+#[test]
+fn test_offset_serialization() {
+    let mut job = Job::new("2023-1-1 8:00".into(), None, None, None).unwrap();
+    job.offset = None;
+    let json = serde_json::to_string(&job).unwrap();
+    assert!(!json.contains("offset"));
+    assert_eq!(serde_json::from_str::<Job>(&json).unwrap(), job);
+
+    job.offset = Some(3600);
+    let json = serde_json::to_string(&job).unwrap();
+    assert!(json.contains("\"offset\":3600"));
+    assert_eq!(serde_json::from_str::<Job>(&json).unwrap(), job);
+}
@@ -1,10 +1,15 @@
 //! An indexed list of jobs which have been extracted from the [Jobs] database
 
 use super::prelude::*;
+use itertools::Itertools;
+use std::collections::BTreeMap;
 
 /// Adds an index to a [Job] reference which stores the original position within the database.
 pub type IndexedJob<'a> = (usize, &'a Job);
 
+/// Relevant tag, billability, invoice reference and day (if rounded per day) to sum hours by.
+type SumKey = (Option<String>, bool, Option<String>, Option<Date>);
+
 /// Selection of jobs from a database.
 #[derive(Debug, Clone)]
 pub struct JobList<'a> {
@@ -12,6 +17,8 @@ pub struct JobList<'a> {
     jobs: Vec<IndexedJob<'a>>,
     /// Copy of the configuration of the original [Jobs] database.
     pub configuration: &'a Configuration,
+    /// Temporal context until which open jobs count when displayed.
+    pub context: Context,
 }
 
 impl<'a> IntoIterator for JobList<'a> {
@@ -28,35 +35,25 @@ impl<'a> From<&'a JobListOwned> for JobList<'a> {
         Self {
             configuration: &list.configuration,
             jobs: list.iter().map(|(n, j)| (*n, j)).collect(),
+            context: list.context.clone(),
         }
     }
 }
 
 impl<'a> std::fmt::Display for JobList<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // format with the settings of the database these jobs stem from
+        locale::init(&self.configuration.settings);
         writeln!(f)?;
         let mut count = 0;
         for (pos, job) in self.iter() {
             writeln!(f, "    Pos: {}", pos + 1)?;
-            job.writeln(f, self.configuration.get(&job.tags))?;
+            job.writeln(f, self.configuration.get(&job.tags), &self.context)?;
             writeln!(f)?;
             count += 1;
         }
-        let pay = {
-            if let Some(pay) = self.pay_overall() {
-                format!(" = ${}", format::pay_pure(pay))
-            } else {
-                String::new()
-            }
-        };
         if count > 1 {
-            writeln!(
-                f,
-                "Total: {} job(s), {} hours{}",
-                self.len(),
-                format::hours_pure(self.hours_overall()),
-                pay,
-            )?;
+            writeln!(f, "Total: {}", self.summary(&self.context))?;
         }
         Ok(())
     }
@@ -64,17 +61,23 @@ impl<'a> std::fmt::Display for JobList<'a> {
 
 impl<'a> JobList<'a> {
     /// Create job list on base of the given database but does not copy the jobs themselves (but it's configuration).
-    pub fn new(jobs: Vec<(usize, &'a Job)>, configuration: &'a Configuration) -> Self {
+    pub fn new(
+        jobs: Vec<(usize, &'a Job)>,
+        configuration: &'a Configuration,
+        context: &Context,
+    ) -> Self {
         Self {
             jobs,
             configuration,
+            context: context.clone(),
         }
     }
     /// Create job list on base of the given database but does not copy the jobs themselves (but it's configuration).
-    pub fn new_from(jobs: &'a Jobs) -> Self {
+    pub fn new_from(jobs: &'a Jobs, context: &Context) -> Self {
         Self {
             jobs: Vec::new(),
             configuration: &jobs.configuration,
+            context: context.clone(),
         }
     }
     /// Add a new job.
@@ -82,7 +85,7 @@ impl<'a> JobList<'a> {
         self.jobs.push((pos, job))
     }
     /// Get read-only iterator over included jobs.
-    pub fn iter(&self) -> core::slice::Iter<'_, IndexedJob> {
+    pub fn iter(&self) -> core::slice::Iter<'_, IndexedJob<'_>> {
         self.jobs.iter()
     }
     /// Return `true` if list is empty.
@@ -123,21 +126,37 @@ impl<'a> JobList<'a> {
         &self.configuration.base
     }
     /// Calculate the overall hours that were spent within this job list (considers resolutions).
-    pub fn hours_overall(&self) -> f64 {
-        let mut hours = 0.0;
-        for (_, job) in &self.jobs {
-            hours += job.hours(self.get_configuration(&job.tags))
-        }
-        hours
+    pub fn hours_overall(&self, context: &Context) -> f64 {
+        self.rounded_hours(context)
+            .iter()
+            .map(|(_, _, _, hours)| hours)
+            .sum()
+    }
+    /// Calculate the overall hours of billable or non-billable jobs within this list.
+    pub fn hours_billable(&self, billable: bool, context: &Context) -> f64 {
+        self.rounded_hours(context)
+            .iter()
+            .filter(|(_, b, _, _)| *b == billable)
+            .map(|(_, _, _, hours)| hours)
+            .sum()
     }
-    /// Calculate the overall costs of the jobs in this list.
-    pub fn pay_overall(&self) -> Option<f64> {
+    /// Calculate the overall hours of invoiced or not invoiced jobs within this list.
+    pub fn hours_invoiced(&self, invoiced: bool, context: &Context) -> f64 {
+        self.rounded_hours(context)
+            .iter()
+            .filter(|(_, _, i, _)| *i == invoiced)
+            .map(|(_, _, _, hours)| hours)
+            .sum()
+    }
+    /// Calculate the overall costs of the billable jobs in this list.
+    pub fn pay_overall(&self, context: &Context) -> Option<f64> {
         let mut pay_sum = 0.0;
         let mut has_payment = false;
-        for (_, job) in &self.jobs {
-            let properties = self.get_configuration(&job.tags);
+        for (properties, billable, _, hours) in self.rounded_hours(context) {
             if let Some(rate) = properties.rate {
-                pay_sum += rate * job.hours(properties);
+                if billable {
+                    pay_sum += rate * hours;
+                }
                 has_payment = true;
             }
         }
@@ -147,4 +166,120 @@ impl<'a> JobList<'a> {
             None
         }
     }
+    /// Summarize count of jobs, hours (billable and non-billable if any) and costs in one line.
+    pub fn summary(&self, context: &Context) -> String {
+        let non_billable = self.hours_billable(false, context);
+        let billable = if non_billable > 0.0 {
+            format!(
+                " ({} billable, {} non-billable)",
+                format::hours_pure(self.hours_billable(true, context)),
+                format::hours_pure(non_billable)
+            )
+        } else {
+            String::new()
+        };
+        let pay = if let Some(pay) = self.pay_overall(context) {
+            format!(" = ${}", format::pay_pure(pay))
+        } else {
+            String::new()
+        };
+        let invoiced = self.hours_invoiced(true, context);
+        let invoiced = if invoiced > 0.0 {
+            format!(
+                ", {} invoiced, {} uninvoiced",
+                format::hours_pure(invoiced),
+                format::hours_pure(self.hours_invoiced(false, context))
+            )
+        } else {
+            String::new()
+        };
+        format!(
+            "{} job(s), {} hours{billable}{pay}{invoiced}",
+            self.len(),
+            format::hours_pure(self.hours_overall(context))
+        )
+    }
+    /// Sum up hours per configuration, billability and invoice and round them at the configured level.
+    fn rounded_hours(&self, context: &Context) -> Vec<(&'a Properties, bool, bool, f64)> {
+        let mut result = Vec::new();
+        // sums of unrounded hours by relevant tag, billability and invoice (and day if rounded per day)
+        let mut sums: BTreeMap<SumKey, f64> = BTreeMap::new();
+        for (_, job) in &self.jobs {
+            let (tag, properties) = self.configuration.get_and_why(&job.tags);
+            let billable = job.is_billable(properties);
+            let invoice = job.invoice.clone();
+            match properties.rounding_level() {
+                RoundingLevel::Job => result.push((
+                    properties,
+                    billable,
+                    job.is_invoiced(),
+                    job.hours(properties, context),
+                )),
+                RoundingLevel::Day => {
+                    for (start, hours) in job.hours_per_day(properties, context) {
+                        *sums
+                            .entry((tag.clone(), billable, invoice.clone(), Some(start.date())))
+                            .or_default() += hours;
+                    }
+                }
+                RoundingLevel::Invoice => {
+                    *sums.entry((tag, billable, invoice, None)).or_default() +=
+                        job.unrounded_hours(properties, context)
+                }
+            }
+        }
+        for ((tag, billable, invoice, _), hours) in sums {
+            let properties = tag
+                .and_then(|tag| self.configuration.tags.get(&tag))
+                .unwrap_or(&self.configuration.base);
+            result.push((
+                properties,
+                billable,
+                invoice.is_some(),
+                properties.round(hours),
+            ));
+        }
+        result
+    }
+    /// Distribute the rounded hours onto the jobs of this list (mapped by their positions).
+    ///
+    /// Every job gets the amount by which it raises the rounded sum of its day or invoice, so
+    /// that the hours of all jobs sum up to the rounded hours like in [JobList::hours_overall].
+    pub fn rounded_hours_per_job(&self, context: &Context) -> BTreeMap<usize, f64> {
+        let mut result = BTreeMap::new();
+        // sums of unrounded hours so far like in `rounded_hours()`
+        let mut sums: BTreeMap<SumKey, f64> = BTreeMap::new();
+        let mut add = |key: SumKey, properties: &Properties, hours: f64| {
+            let sum = sums.entry(key).or_default();
+            let before = properties.round(*sum);
+            *sum += hours;
+            properties.round(*sum) - before
+        };
+        for (pos, job) in self.jobs.iter().sorted_by(|l, r| l.1.cmp(r.1)) {
+            let (tag, properties) = self.configuration.get_and_why(&job.tags);
+            let billable = job.is_billable(properties);
+            let invoice = job.invoice.clone();
+            let hours = match properties.rounding_level() {
+                RoundingLevel::Job => job.hours(properties, context),
+                RoundingLevel::Day => job
+                    .hours_per_day(properties, context)
+                    .into_iter()
+                    .map(|(start, hours)| {
+                        add(
+                            (tag.clone(), billable, invoice.clone(), Some(start.date())),
+                            properties,
+                            hours,
+                        )
+                    })
+                    .sum(),
+                RoundingLevel::Invoice => add(
+                    (tag, billable, invoice, None),
+                    properties,
+                    job.unrounded_hours(properties, context),
+                ),
+            };
+            result.insert(*pos, hours);
+        }
+        result
+    }
 }
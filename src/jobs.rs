@@ -109,23 +109,25 @@ impl Jobs {
         check: Checks,
         context: &Context,
     ) -> Result<Operation, Error> {
-        let mut operation = self.interpret(command)?;
+        // parse and format dates and times with the settings of this database
+        locale::init(&self.configuration.settings);
+        let mut operation = self.interpret(command, context)?;
         self.operate(w, &mut operation, check, context)?;
         Ok(operation)
     }
     /// Get a list of all jobs in database
-    pub fn all(&self) -> JobList {
+    pub fn all(&self, context: &Context) -> JobList<'_> {
         let result: Vec<IndexedJob> = self.iter().enumerate().collect();
-        JobList::new(result, &self.configuration)
+        JobList::new(result, &self.configuration, context)
     }
     /// Generate a list of some jobs.
-    pub fn list(&self, positions: &Positions) -> JobList {
+    pub fn list(&self, positions: &Positions, context: &Context) -> JobList<'_> {
         let result: Vec<IndexedJob> = self
             .iter()
             .enumerate()
             .filter(|(p, _)| positions.contains(p))
             .collect();
-        JobList::new(result, &self.configuration)
+        JobList::new(result, &self.configuration, context)
     }
     /// Collect all tags within the database
     pub fn tags(&self) -> TagSet {
@@ -137,16 +139,37 @@ impl Jobs {
     }
     // public version of filter fpr testing
     #[cfg(test)]
-    pub fn _filter(&self, range: &Range, tags: &TagSet) -> Result<JobList, Error> {
-        self.filter(range, tags)
+    pub fn _filter(
+        &self,
+        range: &Range,
+        tags: &TagSet,
+        context: &Context,
+    ) -> Result<JobList<'_>, Error> {
+        self.filter(range, tags, context)
     }
     /// Filter jobs by range and tags and return a job list with the result.
     /// Deleted jobs will be omitted.
-    fn filter(&self, range: &Range, tags: &TagSet) -> Result<JobList, Error> {
-        let mut jobs = JobList::new_from(self);
+    fn filter(
+        &self,
+        range: &Range,
+        tags: &TagSet,
+        context: &Context,
+    ) -> Result<JobList<'_>, Error> {
+        self.select(range, tags, false, context)
+    }
+    /// Filter jobs by range and tags and return a job list with the result.
+    /// Only deleted jobs will be taken if `deleted` is `true` or omitted otherwise.
+    fn select(
+        &self,
+        range: &Range,
+        tags: &TagSet,
+        deleted: bool,
+        context: &Context,
+    ) -> Result<JobList<'_>, Error> {
+        let mut jobs = JobList::new_from(self, context);
         for (n, job) in self.jobs.iter().enumerate() {
-            // sort out any deleted jobs
-            if job.is_deleted() {
+            // sort out any deleted (or not deleted) jobs
+            if job.is_deleted() != deleted {
                 continue;
             }
             let mut tag_ok = true;
@@ -199,16 +222,16 @@ impl Jobs {
         }
         Ok(jobs)
     }
-    /// copy message from last jobs or ask user to enter one.
-    fn copy_last_or_enter_message(
-        &self,
+    /// copy message from template job or ask user to enter one.
+    fn copy_template_or_enter_message(
+        template: Option<&Job>,
         message: Option<Option<String>>,
     ) -> Result<Option<String>, Error> {
         // check if parameter -m was not given
         if message.is_none() {
-            // check if there is a last job
-            if let Some(last) = self.last() {
-                Ok(last.message.clone())
+            // check if there is a template job
+            if let Some(template) = template {
+                Ok(template.message.clone())
             } else {
                 Err(Error::DatabaseEmpty)
             }
@@ -216,7 +239,7 @@ impl Jobs {
             // use given message
             Ok(Some(message))
         } else {
-            // no message via argument nor via last job -> please enter one
+            // no message via argument nor via template job -> please enter one
             Self::check_force_enter_message(message)
         }
     }
@@ -227,18 +250,66 @@ impl Jobs {
         }
         Ok(message.flatten())
     }
-    /// Modify tags of the last job.
-    fn modify_last_tags_or_given(&self, tags: Option<TagSet>) -> Result<Option<TagSet>, Error> {
-        if let Some(last) = self.last() {
+    /// Modify tags of the template job.
+    fn modify_template_tags_or_given(
+        template: Option<&Job>,
+        tags: Option<TagSet>,
+    ) -> Option<TagSet> {
+        if let Some(template) = template {
             if let Some(tags) = &tags {
-                return Ok(Some(last.tags.modify(tags)));
+                return Some(template.tags.modify(tags));
+            }
+            return Some(template.tags.clone());
+        }
+        tags
+    }
+    /// Find the job which message and tags shall be copied from.
+    fn template(&self, template: &Template) -> Result<Option<&Job>, Error> {
+        match template {
+            Template::Last => Ok(self.last()),
+            Template::Position(pos) => match self.get(*pos) {
+                Some(job) if !job.is_deleted() => Ok(Some(job)),
+                _ => Err(Error::JobNotFound(*pos)),
+            },
+            Template::Search(search) => {
+                let term = search.to_lowercase();
+                self.jobs
+                    .iter()
+                    .rev()
+                    .filter(|job| !job.is_deleted())
+                    .find(|job| {
+                        job.message
+                            .as_ref()
+                            .is_some_and(|message| message.to_lowercase().contains(&term))
+                            || job.tags.0.iter().any(|tag| tag.to_lowercase() == term)
+                    })
+                    .map(Some)
+                    .ok_or(Error::TemplateNotFound(search.clone()))
+            }
+            Template::Pick => Err(Error::PickTemplate),
+        }
+    }
+    /// Get positions of the latest jobs with distinct messages and tags (latest first).
+    pub fn templates(&self, count: usize) -> Vec<usize> {
+        let mut result: Vec<usize> = Vec::new();
+        for (pos, job) in self.jobs.iter().enumerate().rev() {
+            if result.len() >= count {
+                break;
+            }
+            if job.is_deleted() {
+                continue;
+            }
+            if !result
+                .iter()
+                .any(|p| self.jobs[*p].message == job.message && self.jobs[*p].tags == job.tags)
+            {
+                result.push(pos);
             }
-            return Ok(Some(last.tags.clone()));
         }
-        Ok(tags)
+        result
     }
     /// Interpret command into an operation.
-    fn interpret(&self, command: &Command) -> Result<Operation, Error> {
+    fn interpret(&self, command: &Command, context: &Context) -> Result<Operation, Error> {
         // process command and potentially get `Some(job)` change
         Ok(match command.clone() {
             Command::Intro => Operation::Intro,
@@ -246,51 +317,64 @@ impl Jobs {
                 start,
                 message,
                 tags,
-            } => Operation::Push(
-                self.jobs.len(),
-                Job::new(start, None, Self::check_force_enter_message(message)?, tags)?,
-            ),
+                billable,
+            } => {
+                let mut job =
+                    Job::new(start, None, Self::check_force_enter_message(message)?, tags)?;
+                job.billable = billable;
+                Operation::Push(self.jobs.len(), job)
+            }
             Command::Add {
                 start,
                 end,
                 message,
                 tags,
-            } => Operation::Push(
-                self.jobs.len(),
-                Job::new(
+                billable,
+            } => {
+                let mut job = Job::new(
                     start,
                     Some(end),
                     Self::check_force_enter_message(message)?,
                     tags,
-                )?,
-            ),
+                )?;
+                job.billable = billable;
+                Operation::Push(self.jobs.len(), job)
+            }
             Command::Back {
                 start,
                 message,
                 tags,
-            } => Operation::Push(
-                self.jobs.len(),
-                Job::new(
+                template,
+                billable,
+            } => {
+                let template = self.template(&template)?;
+                let mut job = Job::new(
                     start,
                     None,
-                    self.copy_last_or_enter_message(message)?,
-                    self.modify_last_tags_or_given(tags)?,
-                )?,
-            ),
+                    Self::copy_template_or_enter_message(template, message)?,
+                    Self::modify_template_tags_or_given(template, tags),
+                )?;
+                job.billable = billable.or(template.and_then(|template| template.billable));
+                Operation::Push(self.jobs.len(), job)
+            }
             Command::BackAdd {
                 start,
                 end,
                 message,
                 tags,
-            } => Operation::Push(
-                self.jobs.len(),
-                Job::new(
+                template,
+                billable,
+            } => {
+                let template = self.template(&template)?;
+                let mut job = Job::new(
                     start,
                     Some(end),
-                    self.copy_last_or_enter_message(message)?,
-                    self.modify_last_tags_or_given(tags)?,
-                )?,
-            ),
+                    Self::copy_template_or_enter_message(template, message)?,
+                    Self::modify_template_tags_or_given(template, tags),
+                )?;
+                job.billable = billable.or(template.and_then(|template| template.billable));
+                Operation::Push(self.jobs.len(), job)
+            }
             Command::End { end, message, tags } => {
                 self.check_open()?;
                 let message = Self::check_force_enter_message(message)?;
@@ -298,8 +382,8 @@ impl Jobs {
                 if let Some((pos, job)) = self.get_open_with_pos() {
                     // clone open job
                     let mut open_job = job.clone();
-                    // finish open job
-                    open_job.end = Some(end);
+                    // finish open job (and maybe it's break)
+                    open_job.finish(end)?;
                     // maybe overwrite message
                     if message.is_some() {
                         open_job.message = message;
@@ -312,13 +396,76 @@ impl Jobs {
                     return Err(Error::NoOpenJob);
                 }
             }
+            Command::Switch {
+                time,
+                message,
+                tags,
+                end_message,
+                billable,
+            } => {
+                if let Some((pos, job)) = self.get_open_with_pos() {
+                    // finish open job
+                    let mut end_job = job.clone();
+                    end_job.finish(time)?;
+                    if end_message.is_some() {
+                        end_job.message = end_message;
+                    }
+                    // start new job with message and tags given or from the open job
+                    let message = match message {
+                        Some(message) => message,
+                        None => job.message.clone(),
+                    };
+                    let tags = if let Some(tags) = tags {
+                        job.tags.modify(&tags)
+                    } else {
+                        job.tags.clone()
+                    };
+                    let mut new_job = Job::new(time, None, message, Some(tags))?;
+                    new_job.billable = billable.or(job.billable);
+                    Operation::Switch(pos, end_job, self.jobs.len(), new_job)
+                } else {
+                    return Err(Error::NoOpenJob);
+                }
+            }
+            Command::Pause { time } => {
+                if let Some((pos, job)) = self.get_open_with_pos() {
+                    let mut open_job = job.clone();
+                    open_job.pause(time)?;
+                    Operation::Pause(pos, open_job)
+                } else {
+                    return Err(Error::NoOpenJob);
+                }
+            }
+            Command::Resume { time } => {
+                if let Some((pos, job)) = self.get_open_with_pos() {
+                    let mut open_job = job.clone();
+                    open_job.resume(time)?;
+                    Operation::Resume(pos, open_job)
+                } else {
+                    return Err(Error::NoOpenJob);
+                }
+            }
             Command::List { range, tags } => Operation::List(
-                self.filter(&range, &tags.clone().into())?.positions(),
+                self.filter(&range, &tags.clone().into(), context)?
+                    .positions(),
+                range,
+                tags,
+            ),
+            Command::Gaps { range, tags } => Operation::Gaps(
+                gaps(
+                    self,
+                    &self.filter(&range, &tags.clone().into(), context)?,
+                    context,
+                ),
                 range,
                 tags,
             ),
+            Command::Doctor => Operation::Doctor(diagnose(self, context)),
+            Command::Remind => Operation::Remind(remind(self, context)),
+            Command::Status { format } => Operation::Status(status(self, &format, context)?),
             Command::Report { range, tags } => Operation::Report(
-                self.filter(&range, &tags.clone().into())?.positions(),
+                self.filter(&range, &tags.clone().into(), context)?
+                    .positions(),
                 range,
                 tags,
             ),
@@ -329,17 +476,21 @@ impl Jobs {
             } => {
                 let tags = tags.into();
                 Operation::ExportCSV(
-                    self.filter(&range, &tags)?.positions(),
+                    self.filter(&range, &tags, context)?.positions(),
                     range,
                     Some(tags),
                     Columns::from(columns),
                 )
             }
             Command::ShowConfiguration => Operation::ShowConfiguration(self.configuration.clone()),
-            Command::SetConfiguration { tags, update } => Operation::Configure(tags, update),
+            Command::SetConfiguration {
+                tags,
+                update,
+                settings,
+            } => Operation::Configure(tags, update, settings),
             Command::LegacyImport { filename } => Operation::Import(filename, 0, TagSet::new()),
             Command::ListTags { range, tags } => {
-                Operation::ListTags(self.filter(&range, &tags.into())?.tags())
+                Operation::ListTags(self.filter(&range, &tags.into(), context)?.tags())
             }
             Command::Edit {
                 pos,
@@ -347,6 +498,7 @@ impl Jobs {
                 end,
                 message,
                 tags,
+                billable,
             } => {
                 // use given pos or the last undeleted job
                 let pos = if let Some(pos) = pos {
@@ -367,10 +519,10 @@ impl Jobs {
                     // maybe overwrite end
                     match end {
                         EndOrDuration::End(end) => {
-                            job.end = Some(end);
+                            job.finish(end)?;
                         }
                         EndOrDuration::Duration(duration) => {
-                            job.end = Some(job.start + duration);
+                            job.finish(job.start + duration)?;
                         }
                         _ => (),
                     }
@@ -382,16 +534,145 @@ impl Jobs {
                     if let Some(tags) = tags {
                         job.tags = job.tags.modify(&tags);
                     }
+                    // maybe overwrite billable flag
+                    if billable.is_some() {
+                        job.billable = billable;
+                    }
                     Operation::Modify(pos, job.clone())
                 } else {
                     return Err(Error::JobNotFound(pos));
                 }
             }
+            Command::EditMany {
+                range,
+                filter,
+                tags,
+                message,
+                shift,
+                billable,
+            } => Operation::ModifyMany(
+                self.filter(&range, &filter.into(), context)?
+                    .iter()
+                    .map(|(pos, old)| {
+                        let mut job = (*old).clone();
+                        if let Some(tags) = &tags {
+                            job.tags = job.tags.modify(tags);
+                        }
+                        if let Some(message) = &message {
+                            job.message = Some(message.clone());
+                        }
+                        if let Some(shift) = shift {
+                            job.shift(chrono::Duration::minutes(shift));
+                        }
+                        if billable.is_some() {
+                            job.billable = billable;
+                        }
+                        (*pos, (*old).clone(), job)
+                    })
+                    .collect(),
+            ),
+            Command::Split {
+                pos,
+                at,
+                message,
+                tags,
+            } => {
+                if let Some(job) = self.get(pos).filter(|job| !job.is_deleted()) {
+                    let (mut first, mut second) = job.cut(at)?;
+                    if let Some(message) = Self::check_force_enter_message(message)? {
+                        // a job without message gets the new one in both parts
+                        if first.message.is_none() {
+                            first.message = Some(message.clone());
+                        }
+                        second.message = Some(message);
+                    }
+                    if let Some(tags) = tags {
+                        second.tags = second.tags.modify(&tags);
+                    }
+                    Operation::Split(pos, first, self.jobs.len(), second)
+                } else {
+                    return Err(Error::JobNotFound(pos));
+                }
+            }
+            Command::TrimBreaks { pos } => {
+                if let Some(job) = self.get(pos).filter(|job| !job.is_deleted()) {
+                    let mut trimmed = job.clone();
+                    if let Some(breaks) = job.trimmed_breaks() {
+                        trimmed.breaks = breaks;
+                    } else if let Some(b) = job.breaks.iter().find(|b| b.start <= job.start) {
+                        return Err(Error::BreakOutsideJob(b.start));
+                    }
+                    Operation::Modify(pos, trimmed)
+                } else {
+                    return Err(Error::JobNotFound(pos));
+                }
+            }
+            Command::Merge { range, message } => {
+                let (pos, mut merged, positions) = self.merged(&range, context)?;
+                if let Some(message) = Self::check_force_enter_message(message)? {
+                    merged.message = Some(message);
+                } else if positions
+                    .iter()
+                    .any(|p| self.jobs[*p].message != self.jobs[pos].message)
+                {
+                    // different messages shall not be joined unasked
+                    return Err(Error::EnterMessage);
+                }
+                Operation::Merge(pos, merged, positions)
+            }
             Command::Delete { range, tags } => {
-                Operation::Delete(self.filter(&range, &tags.into())?.positions())
+                Operation::Delete(self.filter(&range, &tags.into(), context)?.positions())
             }
+            Command::Restore { range, tags } => Operation::Restore(
+                self.select(&range, &tags.into(), true, context)?
+                    .positions(),
+            ),
+            Command::Invoice {
+                range,
+                tags,
+                reference,
+            } => Operation::Invoice(
+                self.filter(&range, &tags.into(), context)?.positions(),
+                reference,
+            ),
         })
     }
+    /// Merge the jobs within a range into the first one.
+    ///
+    /// Returns position of the first job, the merged job and the positions of the other jobs.
+    fn merged(&self, range: &Range, context: &Context) -> Result<(usize, Job, Positions), Error> {
+        let list = self.filter(range, &TagSet::new(), context)?;
+        let mut jobs: Vec<&IndexedJob> = list.iter().collect();
+        if jobs.len() < 2 {
+            return Err(Error::MergeTooFew(jobs.len()));
+        }
+        jobs.sort_by(|l, r| l.1.cmp(r.1));
+        // jobs must follow each other without any other job between them
+        for pair in jobs.windows(2) {
+            let ((_, previous), (_, next)) = (pair[0], pair[1]);
+            if let Some((_, between)) = self.iter().enumerate().find(|(pos, job)| {
+                !job.is_deleted()
+                    && !jobs.iter().any(|(p, _)| p == pos)
+                    && job.start > previous.start
+                    && job.start < next.start
+            }) {
+                return Err(Error::NotAdjacent(
+                    previous.end.unwrap_or(previous.start),
+                    between.start,
+                ));
+            }
+        }
+        let (pos, first) = jobs[0];
+        let mut merged = (*first).clone();
+        for (_, job) in &jobs[1..] {
+            merged.join(job)?;
+        }
+        Ok((
+            *pos,
+            merged,
+            jobs[1..].iter().map(|(pos, _)| *pos).collect(),
+        ))
+    }
     /// get start date of the first job (which is not deleted)
     fn first_date(&self) -> Option<Date> {
         self.first().map(|first| first.start.date())
@@ -450,7 +731,107 @@ impl Jobs {
                     self.modified = true;
                 }
             }
+            Operation::Switch(end_pos, end_job, pos, job) => {
+                // check position
+                assert!(*pos == self.jobs.len());
+                // finished jobs need message
+                if end_job.message.is_none() {
+                    return Err(Error::EnterMessage);
+                }
+                // check ended job consistency
+                checks.check(self, Some(*end_pos), end_job, context)?;
+                // check new job against database with the ended job
+                let open_job = std::mem::replace(&mut self.jobs[*end_pos], end_job.clone());
+                if let Err(err) = checks.check(self, None, job, context) {
+                    self.jobs[*end_pos] = open_job;
+                    return Err(err);
+                }
+                // add new job to database
+                self.push(job.clone());
+                self.modified = true;
+            }
+            Operation::Split(pos, first, new_pos, second) => {
+                // check position
+                assert!(*new_pos == self.jobs.len());
+                // check if job is invoiced
+                checks.check_locked(self, [*pos])?;
+                // both parts need a message when they are finished
+                if first.message.is_none() || (second.message.is_none() && !second.is_open()) {
+                    return Err(Error::EnterMessage);
+                }
+                // check second part against database with the first part
+                let job = std::mem::replace(&mut self.jobs[*pos], first.clone());
+                if let Err(err) = checks.check(self, None, second, context) {
+                    self.jobs[*pos] = job;
+                    return Err(err);
+                }
+                self.push(second.clone());
+                self.modified = true;
+            }
+            Operation::Merge(pos, merged, positions) => {
+                // check if any of the merged jobs is invoiced
+                checks.check_locked(self, positions.iter().copied())?;
+                // check merged job against database without the merged ones
+                let mut updated = self.clone();
+                for p in positions.iter() {
+                    updated.jobs[*p].delete(context);
+                }
+                checks.check(&updated, Some(*pos), merged, context)?;
+                updated.jobs[*pos] = merged.clone();
+                self.jobs = updated.jobs;
+                self.modified = true;
+            }
+            Operation::Pause(pos, job) | Operation::Resume(pos, job) => {
+                // check job consistency
+                checks.check(self, Some(*pos), job, context)?;
+                // overwrite open job in database
+                self.jobs[*pos] = job.clone();
+                self.modified = true;
+            }
+            Operation::ModifyMany(modified) => {
+                let mut warnings = Vec::new();
+                // maybe confirm modification
+                if checks.has(Check::ConfirmModification) {
+                    warnings.push(Warning::ConfirmModification(modified.clone()));
+                }
+                // check for unknown tags against the original database
+                if checks.has(Check::UnknownTags) {
+                    let tags = self.tags();
+                    let mut unknown_tags = TagSet::new();
+                    for (_, _, job) in modified.iter() {
+                        unknown_tags.insert_many(job.tags.filter(|tag| !tags.contains(tag)));
+                    }
+                    if !unknown_tags.is_empty() {
+                        warnings.push(Warning::UnknownTags(unknown_tags));
+                    }
+                }
+                // check every job against the database including all the modifications
+                let checks = checks.without(Check::UnknownTags);
+                let mut updated = self.jobs.clone();
+                for (pos, _, job) in modified.iter() {
+                    updated[*pos] = job.clone();
+                }
+                let updated = Jobs {
+                    modified: false,
+                    jobs: updated,
+                    configuration: self.configuration.clone(),
+                };
+                for (pos, _, job) in modified.iter() {
+                    match checks.check(&updated, Some(*pos), job, context) {
+                        Err(Error::Warnings(w)) => warnings.extend(w),
+                        Err(err) => return Err(err),
+                        Ok(()) => (),
+                    }
+                }
+                if !warnings.is_empty() {
+                    return Err(Error::Warnings(warnings));
+                }
+                self.jobs = updated.jobs;
+                self.modified = !modified.is_empty();
+            }
             Operation::Delete(positions) => {
+                // check if any of the jobs is invoiced
+                checks.check_locked(self, positions.iter().copied())?;
                 // maybe confirm deletion
                 if checks.has(Check::ConfirmDeletion) {
                     return Err(Error::Warnings(vec![Warning::ConfirmDeletion(
@@ -464,19 +845,86 @@ impl Jobs {
                     }
                 }
             }
+            Operation::Restore(positions) => {
+                // check if any of the jobs is invoiced
+                checks.check_locked(self, positions.iter().copied())?;
+                // check every job against the database including all restored jobs
+                let mut updated = self.clone();
+                for pos in positions.iter() {
+                    updated.jobs[*pos].undelete();
+                }
+                let mut warnings = Vec::new();
+                for pos in positions.iter() {
+                    match checks.check(&updated, Some(*pos), &updated.jobs[*pos], context) {
+                        Err(Error::Warnings(w)) => warnings.extend(w),
+                        Err(err) => return Err(err),
+                        Ok(()) => (),
+                    }
+                }
+                if !warnings.is_empty() {
+                    return Err(Error::Warnings(warnings));
+                }
+                for pos in positions.iter() {
+                    self.jobs[*pos].undelete();
+                    self.modified = true;
+                }
+            }
+            Operation::Invoice(positions, reference) => {
+                // jobs may not be invoiced twice and invoices may only be removed if unlocked
+                checks.check_locked(self, positions.iter().copied())?;
+                // open jobs may still change
+                if reference.is_some() {
+                    if let Some(pos) = positions.iter().find(|pos| self.jobs[**pos].is_open()) {
+                        return Err(Error::InvoiceOpenJob(*pos));
+                    }
+                }
+                for pos in positions.iter() {
+                    self.jobs[*pos].invoice = reference.clone();
+                    self.modified = true;
+                }
+            }
             Operation::Import(filename, count, new_tags) => {
                 (*count, *new_tags) = self.legacy_import(filename)?;
                 self.modified = *count > 0;
             }
-            Operation::Configure(tags, update) => {
+            Operation::Configure(tags, update, settings) => {
                 self.modified = self.configuration.set(tags, update);
+                if self.configuration.set_settings(settings) {
+                    self.modified = true;
+                }
+                // changed settings take effect immediately
+                locale::init(&self.configuration.settings);
             }
             Operation::List(positions, _, _) => {
-                write!(w, "{}", self.list(positions))?;
+                write!(w, "{}", self.list(positions, context))?;
+            }
+            Operation::Gaps(gaps, _, _) => {
+                writeln!(w)?;
+                for (n, gap) in gaps.iter().enumerate() {
+                    writeln!(w, "{:>4}) {gap}", n + 1)?;
+                }
+                if !gaps.is_empty() {
+                    writeln!(w)?;
+                }
+            }
+            Operation::Doctor(problems) => {
+                writeln!(w)?;
+                for (n, problem) in problems.iter().enumerate() {
+                    writeln!(w, "{:>4}) {problem}", n + 1)?;
+                    if let Some(fix) = problem.fix() {
+                        writeln!(w, "      Fix: {fix}")?;
+                    }
+                }
+                if !problems.is_empty() {
+                    writeln!(w)?;
+                }
+            }
+            Operation::Status(status) if !status.is_empty() => writeln!(w, "{status}")?,
+            Operation::Report(positions, _, _) => {
+                report(w, &self.list(positions, context), context)?
             }
-            Operation::Report(positions, _, _) => report(w, &self.list(positions), context)?,
             Operation::ExportCSV(positions, _, _, columns) => {
-                export_csv(w, &self.list(positions), columns, context)?
+                export_csv(w, &self.list(positions, context), columns, context)?
             }
             _ => (),
         }
@@ -485,7 +933,7 @@ impl Jobs {
     /// Check if there is an open job in the database.
     fn check_finished(&self) -> Result<(), Error> {
         if let Some((pos, job)) = self.get_open_with_pos() {
-            return Err(Error::OpenJob(pos, job.clone()));
+            return Err(Error::OpenJob(pos, Box::new(job.clone())));
         }
         Ok(())
     }
@@ -504,6 +952,23 @@ impl Jobs {
         }
         Err(Error::NoOpenJob)
     }
+    /// Return the current message of the job which the given command would change the message of.
+    pub fn message_to_edit(&self, command: &Command, context: &Context) -> Option<String> {
+        let job = match command {
+            Command::Edit { pos: Some(pos), .. } => self.get(*pos),
+            Command::Edit { pos: None, .. } => self.last(),
+            Command::End { .. } | Command::Switch { .. } => self.get_open(),
+            Command::Split { pos, .. } => self.get(*pos),
+            Command::Merge { range, .. } => {
+                return self
+                    .merged(range, context)
+                    .ok()
+                    .and_then(|(_, job, _)| job.message);
+            }
+            _ => None,
+        };
+        job.and_then(|job| job.message.clone())
+    }
     /// Return start time of open job in database if there is any.
     pub fn open_start(&self) -> Option<DateTime> {
         if let Some(job) = self.get_open() {
@@ -521,8 +986,42 @@ impl Jobs {
         let versioned = serde_json::from_reader::<_, Versioned<Jobs>>(reader)
             .map_err(Error::Json)?;
         tags::init(&versioned.jobs);
+        locale::init(&versioned.jobs.configuration.settings);
         Ok(versioned.jobs)
     }
+    /// Load only the open job (and it's position) from a database file and initialize the
+    /// settings of that database.
+    ///
+    /// All other jobs are just scanned but not parsed which keeps this fast on large databases.
+    pub fn load_open(filename: &str) -> Result<Option<(usize, Job)>, Error> {
+        /// Database with unparsed jobs.
+        #[derive(Deserialize)]
+        struct Unparsed<'a> {
+            #[serde(borrow)]
+            jobs: Vec<&'a serde_json::value::RawValue>,
+            configuration: Configuration,
+        }
+        /// What decides if a job is open.
+        #[derive(Deserialize)]
+        struct Peek {
+            end: Option<serde::de::IgnoredAny>,
+            #[serde(default)]
+            deleted: Option<serde::de::IgnoredAny>,
+        }
+        let content = std::fs::read_to_string(filename).map_err(Error::Io)?;
+        let unparsed: Unparsed = serde_json::from_str(&content).map_err(Error::Json)?;
+        locale::init(&unparsed.configuration.settings);
+        for (pos, job) in unparsed.jobs.iter().enumerate().rev() {
+            let peek: Peek = serde_json::from_str(job.get()).map_err(Error::Json)?;
+            if peek.end.is_none() && peek.deleted.is_none() {
+                return Ok(Some((
+                    pos,
+                    serde_json::from_str(job.get()).map_err(Error::Json)?,
+                )));
+            }
+        }
+        Ok(None)
+    }
     /// Save database into file.
     pub fn save(&mut self, filename: &str) -> Result<(), Error> {
         let file = File::options()
@@ -553,7 +1052,7 @@ impl Jobs {
                 continue;
             }
             writeln!(f, "\n    Pos: {}", n + 1)?;
-            job.writeln(f, self.configuration.get(&job.tags))?;
+            job.writeln(f, self.configuration.get(&job.tags), &Context::now())?;
         }
         Ok(())
     }
@@ -567,8 +1066,8 @@ impl Jobs {
         let tags = self.tags();
         let mut count = 0;
         let mut new_tags = TagSet::new();
+        let re = Regex::new(r#""(.*)";"(.*)";"(.*)";"(.*)"$"#).unwrap();
         for line in reader.lines() {
-            let re = Regex::new(r#""(.*)";"(.*)";"(.*)";"(.*)"$"#).unwrap();
             for cap in re.captures_iter(&line.unwrap()) {
                 let start = DateTime::from_rfc3339(&cap[1])?;
                 let end = cap[2].to_string();
@@ -620,12 +1119,14 @@ impl Jobs {
         let mut r = self.filter(
             &Range::Since(context.date().first_day_of_month()),
             &TagSet::new(),
+            context,
         )?;
         if r.len() < 5 {
             eprintln!("Last month till today:\n");
             r = self.filter(
                 &Range::Since(context.date().first_day_of_previous_month()),
                 &TagSet::new(),
+                context,
             )?;
         } else {
             eprintln!("--------------------------- This Month ----------------------------\n");
@@ -642,7 +1143,12 @@ impl Jobs {
 
         eprint!(
             "{}",
-            self.list(&self.filter(&Range::Count(1), &TagSet::new())?.positions(),)
+            self.list(
+                &self
+                    .filter(&Range::Count(1), &TagSet::new(), context)?
+                    .positions(),
+                context,
+            )
         );
 
         // print help
@@ -678,6 +1184,7 @@ impl Default for Jobs {
     }
 }
 
+/// Open jobs are displayed with the hours worked until now.
 impl std::fmt::Display for Jobs {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.writeln(f, |_, _| true)
@@ -7,26 +7,32 @@ pub mod command;
 pub mod configuration;
 pub mod context;
 pub mod date_time;
+pub mod doctor;
 pub mod duration;
 pub mod error;
 pub mod export;
 pub mod format;
+pub mod gap;
 pub mod job;
 pub mod job_list;
 pub mod jobs;
+pub mod locale;
 pub mod operation;
 pub mod partial_date_time;
 pub mod positions;
 pub mod range;
+pub mod reminder;
 pub mod reports;
+pub mod status;
 pub mod tag_set;
 pub mod tags;
 
 /// What *jobber* needs.
 pub mod prelude {
     pub use super::{
-        check::*, command::*, configuration::*, context::*, date_time::*, duration::*, error::*,
-        export::*, format, job::*, job_list::*, jobs::*, operation::*, partial_date_time::*,
-        positions::*, range::*, reports::*, tag_set::*, tags,
+        check::*, command::*, configuration::*, context::*, date_time::*, doctor::*, duration::*,
+        error::*, export::*, format, gap::*, job::*, job_list::*, jobs::*, locale, operation::*,
+        partial_date_time::*, positions::*, range::*, reminder::*, reports::*, status::*,
+        tag_set::*, tags,
     };
 }
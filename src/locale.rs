@@ -0,0 +1,85 @@
+//! Register of the database's settings to parse and format dates and times.
+//!
+//! The register is kept per thread and gets initialized whenever a database is loaded or processed
+//! and whenever jobs of a database get reported, exported or listed (because they may stem from
+//! another database than the one which was loaded last).
+
+use super::prelude::*;
+use std::cell::RefCell;
+
+/// Settings of a database together with the already parsed time zone.
+struct Register {
+    settings: Settings,
+    zone: Zone,
+}
+
+thread_local! {
+    /// Settings register.
+    static SETTINGS: RefCell<Register> = const {
+        RefCell::new(Register {
+            settings: Settings::new(),
+            zone: Zone::Local,
+        })
+    };
+}
+
+/// Initialize settings register `SETTINGS` from the settings of a database.
+pub fn init(settings: &Settings) {
+    let settings = settings.clone();
+    // parse time zone only once because it is needed for every conversion
+    let zone = if let Some(zone) = &settings.time_zone {
+        Zone::parse(zone).unwrap_or(Zone::Local)
+    } else {
+        Zone::Local
+    };
+    SETTINGS.with(|register| *register.borrow_mut() = Register { settings, zone })
+}
+
+/// Read from the settings register.
+fn with<T>(f: impl FnOnce(&Settings) -> T) -> T {
+    SETTINGS.with(|register| f(&register.borrow().settings))
+}
+
+/// Get the preferred order of day, month and year.
+pub fn date_order() -> DateOrder {
+    with(|settings| settings.date_order.unwrap_or_default())
+}
+
+/// Get the preferred clock.
+pub fn clock() -> Clock {
+    with(|settings| settings.clock.unwrap_or_default())
+}
+
+/// Get the reporting time zone.
+pub fn zone() -> Zone {
+    SETTINGS.with(|register| register.borrow().zone)
+}
+
+/// Get the time at which a work day ends and the next one begins as offset to midnight.
+pub fn day_boundary() -> chrono::Duration {
+    with(|settings| {
+        if let Some(day_boundary) = settings.day_boundary {
+            day_boundary.signed_duration_since(chrono::NaiveTime::MIN)
+        } else {
+            chrono::Duration::zero()
+        }
+    })
+}
+
+/// Get format string to display date and time.
+pub fn date_time_format() -> String {
+    format!(
+        "%a {}, {}",
+        date_order().display_format(),
+        clock().time_format()
+    )
+}
+
+/// Get format string to export date and time.
+pub fn export_format() -> String {
+    if let Some(export_format) = with(|settings| settings.export_format.clone()) {
+        export_format
+    } else {
+        format!("{} {}", date_order().export_format(), clock().time_format())
+    }
+}
@@ -2,6 +2,7 @@
 
 use super::prelude::*;
 use rand::Rng;
+use serde_json::json;
 
 const MOTD: &[&str] = &["And don't work too much!", "Work smarter, not harder."];
 
@@ -16,14 +17,38 @@ pub enum Operation {
     Push(usize, Job),
     /// Change an existing `Job` at index `usize` into database but return error if message is missing.
     Modify(usize, Job),
+    /// End the open `Job` at index `usize` and push a new `Job` at another index into database.
+    Switch(usize, Job, usize, Job),
+    /// Begin a break in the open `Job` at index `usize`.
+    Pause(usize, Job),
+    /// End the break in the open `Job` at index `usize`.
+    Resume(usize, Job),
+    /// Change existing jobs at the given indexes from the original into the modified `Job`.
+    ModifyMany(Vec<(usize, Job, Job)>),
+    /// Change an existing `Job` at index `usize` into the first part and push the second part at another index.
+    Split(usize, Job, usize, Job),
+    /// Change an existing `Job` at index `usize` into a merged one and delete the others.
+    Merge(usize, Job, Positions),
     /// Remove jobs from
     Delete(Positions),
+    /// Restore deleted jobs
+    Restore(Positions),
+    /// Mark jobs as invoiced by a reference or remove the invoice if `None`
+    Invoice(Positions, Option<String>),
     /// Import file
     Import(String, usize, TagSet),
     /// Change configuration
-    Configure(Option<TagSet>, Properties),
+    Configure(Option<TagSet>, Properties, Settings),
     /// List jobs
     List(Positions, Range, Option<TagSet>),
+    /// List gaps between jobs
+    Gaps(Vec<Gap>, Range, Option<TagSet>),
+    /// List problems within the database
+    Doctor(Vec<Problem>),
+    /// Remind of a forgotten open job
+    Remind(Option<Reminder>),
+    /// Status of the open job
+    Status(String),
     /// Report jobs
     Report(Positions, Range, Option<TagSet>),
     /// Export jobs
@@ -36,10 +61,126 @@ pub enum Operation {
 
 impl Operation {
     pub fn reports_open_job(&self) -> bool {
-        matches!(self, Operation::Intro | Operation::Push(_, _))
+        matches!(
+            self,
+            Operation::Intro
+                | Operation::Push(_, _)
+                | Operation::Switch(_, _, _, _)
+                | Operation::Pause(_, _)
+                | Operation::Resume(_, _)
+                | Operation::Remind(_)
+                | Operation::Status(_)
+        )
+    }
+    /// Describe the result of this operation in a stable JSON format (positions are counted from 1).
+    pub fn to_json(&self, jobs: &Jobs, context: &Context) -> serde_json::Value {
+        let job = |pos: &usize, job: &Job| json!({ "pos": pos + 1, "job": job });
+        let list = |positions: &Positions| -> Vec<serde_json::Value> {
+            positions.iter().map(|pos| job(pos, &jobs[*pos])).collect()
+        };
+        match self {
+            Operation::None => json!({ "operation": "none" }),
+            Operation::Intro => json!({ "operation": "intro" }),
+            Operation::Push(pos, j) => json!({ "operation": "push", "jobs": [job(pos, j)] }),
+            Operation::Modify(pos, j) => json!({ "operation": "modify", "jobs": [job(pos, j)] }),
+            Operation::Switch(end_pos, end_job, pos, j) => json!({
+                "operation": "switch",
+                "jobs": [job(end_pos, end_job), job(pos, j)]
+            }),
+            Operation::Pause(pos, j) => json!({ "operation": "pause", "jobs": [job(pos, j)] }),
+            Operation::Resume(pos, j) => json!({ "operation": "resume", "jobs": [job(pos, j)] }),
+            Operation::ModifyMany(modified) => json!({
+                "operation": "modify_many",
+                "jobs": modified.iter().map(|(pos, _, j)| job(pos, j)).collect::<Vec<_>>()
+            }),
+            Operation::Split(pos, first, new_pos, second) => json!({
+                "operation": "split",
+                "jobs": [job(pos, first), job(new_pos, second)]
+            }),
+            Operation::Merge(pos, merged, positions) => json!({
+                "operation": "merge",
+                "jobs": [job(pos, merged)],
+                "deleted": humanize(positions)
+            }),
+            Operation::Delete(positions) => json!({
+                "operation": "delete",
+                "positions": humanize(positions)
+            }),
+            Operation::Restore(positions) => json!({
+                "operation": "restore",
+                "jobs": list(positions)
+            }),
+            Operation::Invoice(positions, reference) => json!({
+                "operation": "invoice",
+                "positions": humanize(positions),
+                "reference": reference
+            }),
+            Operation::Import(filename, count, new_tags) => json!({
+                "operation": "import",
+                "filename": filename,
+                "count": count,
+                "new_tags": new_tags
+            }),
+            Operation::Configure(tags, properties, settings) => json!({
+                "operation": "configure",
+                "tags": tags,
+                "properties": properties,
+                "settings": settings
+            }),
+            Operation::List(positions, _, _) => json!({
+                "operation": "list",
+                "jobs": list(positions)
+            }),
+            Operation::Gaps(gaps, _, _) => json!({
+                "operation": "gaps",
+                "gaps": gaps.iter().map(|gap| json!({
+                    "start": gap.start,
+                    "end": gap.end,
+                    "before": gap.before.map(|pos| pos + 1),
+                    "after": gap.after.map(|pos| pos + 1)
+                })).collect::<Vec<_>>()
+            }),
+            Operation::Doctor(problems) => json!({
+                "operation": "doctor",
+                "problems": problems.iter().map(|problem| json!({
+                    "problem": problem.to_string(),
+                    "fix": problem.fix().map(|fix| fix.to_string())
+                })).collect::<Vec<_>>()
+            }),
+            Operation::Remind(reminder) => json!({
+                "operation": "remind",
+                "reminder": reminder.as_ref().map(|reminder| json!({
+                    "pos": reminder.pos() + 1,
+                    "end": reminder.end(),
+                    "reason": reminder.to_string()
+                }))
+            }),
+            Operation::Status(status) => json!({ "operation": "status", "status": status }),
+            Operation::Report(positions, _, _) => json!({
+                "operation": "report",
+                "jobs": list(positions),
+                "hours": jobs.list(positions, context).hours_overall(context),
+                "pay": jobs.list(positions, context).pay_overall(context)
+            }),
+            Operation::ExportCSV(positions, _, _, columns) => json!({
+                "operation": "export",
+                "positions": humanize(positions),
+                "columns": columns.to_string()
+            }),
+            Operation::ListTags(tags) => json!({ "operation": "list_tags", "tags": tags }),
+            Operation::ShowConfiguration(configuration) => json!({
+                "operation": "show_configuration",
+                "configuration": configuration
+            }),
+        }
     }
 }
 
+/// Convert positions into positions counted from 1.
+fn humanize(positions: &Positions) -> Vec<usize> {
+    positions.iter().map(|pos| pos + 1).collect()
+}
+
 impl std::fmt::Display for Operation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -63,6 +204,44 @@ impl std::fmt::Display for Operation {
                     write!(f, "Modified job:\n\n    Pos: {}\n{job}", position + 1)
                 }
             }
+            Operation::Switch(end_position, end_job, position, job) => {
+                write!(
+                    f,
+                    "Ended job:\n\n    Pos: {}\n{end_job}\nStarted new job:\n\n    Pos: {}\n{job}",
+                    end_position + 1,
+                    position + 1
+                )
+            }
+            Operation::Pause(position, job) => {
+                write!(f, "Paused open job:\n\n    Pos: {}\n{job}", position + 1)
+            }
+            Operation::Resume(position, job) => {
+                write!(f, "Resumed open job:\n\n    Pos: {}\n{job}", position + 1)
+            }
+            Operation::ModifyMany(jobs) => {
+                write!(
+                    f,
+                    "Modified job(s) at position(s): {}\n{}",
+                    Positions::from_iter(jobs.iter().map(|(pos, _, _)| *pos)).into_ranges(),
+                    format::diffs(jobs)
+                )
+            }
+            Operation::Split(position, job, new_position, new_job) => {
+                write!(
+                    f,
+                    "Split job:\n\n    Pos: {}\n{job}\n    Pos: {}\n{new_job}",
+                    position + 1,
+                    new_position + 1
+                )
+            }
+            Operation::Merge(position, job, positions) => {
+                write!(
+                    f,
+                    "Merged job(s) at position(s) {} into:\n\n    Pos: {}\n{job}",
+                    positions.into_ranges(),
+                    position + 1
+                )
+            }
             Operation::Delete(positions) => {
                 write!(
                     f,
@@ -70,6 +249,27 @@ impl std::fmt::Display for Operation {
                     positions.into_ranges()
                 )
             }
+            Operation::Restore(positions) => {
+                write!(
+                    f,
+                    "Restoring job(s) at position(s): {}",
+                    positions.into_ranges()
+                )
+            }
+            Operation::Invoice(positions, Some(reference)) => {
+                write!(
+                    f,
+                    "Invoiced job(s) at position(s) {} with reference '{reference}'",
+                    positions.into_ranges()
+                )
+            }
+            Operation::Invoice(positions, None) => {
+                write!(
+                    f,
+                    "Removed invoice from job(s) at position(s): {}",
+                    positions.into_ranges()
+                )
+            }
             Operation::Import(filename, count, new_tags) => {
                 if new_tags.is_empty() {
                     write!(f, "Imported {count} jobs from {filename}.")
@@ -80,20 +280,26 @@ impl std::fmt::Display for Operation {
                     )
                 }
             }
-            Operation::Configure(tags, config) => {
-                if let Some(tags) = tags {
-                    write!(
-                        f,
-                        "Changed the following configuration values for tag(s) {}:\n\n{}",
-                        tags, config
-                    )
-                } else {
-                    write!(
-                        f,
-                        "Changed the following default configuration values:\n\n{}",
-                        config
-                    )
+            Operation::Configure(tags, config, settings) => {
+                if !config.is_empty() {
+                    if let Some(tags) = tags {
+                        write!(
+                            f,
+                            "Changed the following configuration values for tag(s) {}:\n\n{}",
+                            tags, config
+                        )?;
+                    } else {
+                        write!(
+                            f,
+                            "Changed the following default configuration values:\n\n{}",
+                            config
+                        )?;
+                    }
                 }
+                if !settings.is_empty() {
+                    write!(f, "Changed the following settings:\n\n{}", settings)?;
+                }
+                Ok(())
             }
             Operation::List(_, range, tags) => {
                 if let Some(tags) = tags {
@@ -103,6 +309,28 @@ impl std::fmt::Display for Operation {
                 }
                 Ok(())
             }
+            Operation::Gaps(gaps, range, tags) => {
+                let tags = if let Some(tags) = tags {
+                    format!(" with tags {tags}")
+                } else {
+                    String::new()
+                };
+                if gaps.is_empty() {
+                    write!(f, "No gaps found in {range}{tags}.")
+                } else {
+                    write!(f, "Found {} gap(s) in {range}{tags}.", gaps.len())
+                }
+            }
+            Operation::Doctor(problems) => {
+                if problems.is_empty() {
+                    write!(f, "No problems found in database.")
+                } else {
+                    write!(f, "Found {} problem(s) in database.", problems.len())
+                }
+            }
+            Operation::Remind(Some(reminder)) => write!(f, "{reminder}"),
+            Operation::Remind(None) => write!(f, "No forgotten open job."),
+            Operation::Status(_) => Ok(()),
             Operation::Report(_, range, tags) => {
                 if let Some(tags) = tags {
                     write!(f, "Reported {range} with tags {tags}.")?;
@@ -127,6 +355,10 @@ impl std::fmt::Display for Operation {
                 }
             }
             Operation::ShowConfiguration(configuration) => {
+                // print settings
+                if !configuration.settings.is_empty() {
+                    writeln!(f, "Settings:\n\n{}", configuration.settings)?;
+                }
                 // print base configurations
                 writeln!(f, "Base Configuration:\n\n{}", configuration.base)?;
                 // print tag wise configurations
@@ -1,7 +1,7 @@
 //! Partial date and time like it is entered by the user.
 
 use super::prelude::*;
-use chrono::{Datelike, Local, TimeZone, Timelike, Utc};
+use chrono::{Datelike, NaiveDate, Timelike, Weekday};
 use regex::Regex;
 
 /// Partial date and time in different flavors.
@@ -30,6 +30,20 @@ pub enum PartialDateTime {
     },
     /// Complete date without time.
     YMD { year: i32, month: u32, day: u32 },
+    /// Offset in minutes relative to the current time (e.g. `-15m` or `+1h`).
+    Offset { minutes: i64 },
+    /// Day relative to today (e.g. `yesterday`) without time.
+    Days { days: i64 },
+    /// Day relative to today with time.
+    DaysHM { days: i64, hour: u32, minute: u32 },
+    /// Latest weekday until today (e.g. `monday`) without time.
+    Weekday { weekday: Weekday },
+    /// Latest weekday until today with time.
+    WeekdayHM {
+        weekday: Weekday,
+        hour: u32,
+        minute: u32,
+    },
 }
 
 impl PartialDateTime {
@@ -62,62 +76,62 @@ impl PartialDateTime {
     /// Enrich `left` partial date with available data from the `right`.
     fn merge(left: Self, right: Self) -> Self {
         if let Self::HM { hour, minute } = left {
-            if let Self::YMD { year, month, day } = right {
-                return Self::YMDHM {
-                    year,
-                    month,
-                    day,
-                    hour,
-                    minute,
-                };
-            } else if let Self::MD { month, day } = right {
-                return Self::MDHM {
-                    month,
-                    day,
-                    hour,
-                    minute,
-                };
-            }
+            Self::merge_hm(right, hour, minute)
         } else if let Self::HM { hour, minute } = right {
-            if let Self::YMD { year, month, day } = left {
-                return Self::YMDHM {
-                    year,
-                    month,
-                    day,
-                    hour,
-                    minute,
-                };
-            } else if let Self::MD { month, day } = left {
-                return Self::MDHM {
-                    month,
-                    day,
-                    hour,
-                    minute,
-                };
-            }
+            Self::merge_hm(left, hour, minute)
+        } else {
+            Self::None
+        }
+    }
+    /// Enrich partial `date` with the given time.
+    fn merge_hm(date: Self, hour: u32, minute: u32) -> Self {
+        match date {
+            Self::YMD { year, month, day } => Self::YMDHM {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+            },
+            Self::MD { month, day } => Self::MDHM {
+                month,
+                day,
+                hour,
+                minute,
+            },
+            Self::Days { days } => Self::DaysHM { days, hour, minute },
+            Self::Weekday { weekday } => Self::WeekdayHM {
+                weekday,
+                hour,
+                minute,
+            },
+            _ => Self::None,
         }
-        Self::None
     }
-    /// Parse date and time from `String`.
+    /// Parse any date format.
+    ///
+    /// Dates which are separated by slashes are read month first unless `order` is [DateOrder::DMY].
+    fn parse_date(dt: &str, order: DateOrder) -> Self {
+        let slashed = match order {
+            DateOrder::DMY => Self::parse_dmy_slash(dt).or(Self::parse_dm_slash(dt)),
+            _ => Self::parse_mdy(dt).or(Self::parse_md(dt)),
+        };
+        Self::parse_dmy(dt).or(slashed.or(Self::parse_ymd(dt)
+            .or(Self::parse_dm(dt).or(Self::parse_days(dt).or(Self::parse_weekday(dt))))))
+    }
+    /// Parse date and time from `String` by using the preferred date order.
     fn parse_date_time(dt: String) -> Self {
-        let dt: Vec<&str> = dt.split(',').collect();
+        Self::parse_date_time_in(dt, locale::date_order())
+    }
+    /// Parse date and time from `String` by using the given date order.
+    fn parse_date_time_in(dt: String, order: DateOrder) -> Self {
+        let dt: Vec<&str> = dt.split([',', ' ']).filter(|dt| !dt.is_empty()).collect();
         match dt.len() {
-            1 => Self::parse_dmy(dt[0]).or(Self::parse_mdy(dt[0]).or(Self::parse_ymd(dt[0])
-                .or(Self::parse_dm(dt[0]).or(Self::parse_md(dt[0]).or(Self::parse_hm(dt[0])))))),
-            2 => {
-                Self::merge(
-                    Self::parse_dmy(dt[0])
-                        .or(Self::parse_mdy(dt[0]).or(Self::parse_ymd(dt[0])
-                            .or(Self::parse_dm(dt[0]).or(Self::parse_md(dt[0]))))),
-                    Self::parse_hm(dt[1]),
-                )
-                .or(Self::merge(
-                    Self::parse_dmy(dt[1])
-                        .or(Self::parse_mdy(dt[1]).or(Self::parse_ymd(dt[1])
-                            .or(Self::parse_dm(dt[1]).or(Self::parse_md(dt[1]))))),
-                    Self::parse_hm(dt[0]),
-                ))
-            }
+            1 => Self::parse_date(dt[0], order)
+                .or(Self::parse_hm(dt[0]).or(Self::parse_offset(dt[0]))),
+            2 => Self::merge(Self::parse_date(dt[0], order), Self::parse_hm(dt[1])).or(
+                Self::merge(Self::parse_date(dt[1], order), Self::parse_hm(dt[0])),
+            ),
             _ => PartialDateTime::None,
         }
     }
@@ -157,6 +171,29 @@ impl PartialDateTime {
         }
         Self::None
     }
+    /// Parse date without year and time from "dd/mm" format.
+    fn parse_dm_slash(dt: &str) -> Self {
+        let re = Regex::new(r"^(\d{1,2})/(\d{1,2})$").unwrap();
+        if let Some(cap) = re.captures_iter(dt).next() {
+            return Self::MD {
+                month: cap[2].parse::<u32>().unwrap(),
+                day: cap[1].parse::<u32>().unwrap(),
+            };
+        }
+        Self::None
+    }
+    /// Parse date without time from "dd/mm/yyyy" format.
+    fn parse_dmy_slash(dt: &str) -> Self {
+        let re = Regex::new(r"^(\d{1,2})/(\d{1,2})/(\d{4})$").unwrap();
+        if let Some(cap) = re.captures_iter(dt).next() {
+            return Self::YMD {
+                year: cap[3].parse::<i32>().unwrap(),
+                month: cap[2].parse::<u32>().unwrap(),
+                day: cap[1].parse::<u32>().unwrap(),
+            };
+        }
+        Self::None
+    }
     /// Parse German date without year and time from "dd.mm.yyyy" format.
     fn parse_dmy(dt: &str) -> Self {
         let re = Regex::new(r"^(\d{1,2})\.(\d{1,2})\.(\d{4})$").unwrap();
@@ -193,51 +230,86 @@ impl PartialDateTime {
         }
         Self::None
     }
+    /// Parse time offset from "-15m", "+1h", "now-1:30" and similar formats.
+    fn parse_offset(dt: &str) -> Self {
+        let re = Regex::new(r"^(?:now)?([+-])(.+)$").unwrap();
+        if let Some(cap) = re.captures_iter(dt).next() {
+            if let Ok(duration) = Duration::parse(cap[2].to_string()) {
+                return Self::Offset {
+                    minutes: match &cap[1] {
+                        "-" => -duration.num_minutes(),
+                        _ => duration.num_minutes(),
+                    },
+                };
+            }
+        }
+        Self::None
+    }
+    /// Parse day relative to today from "today", "yesterday" or "tomorrow".
+    fn parse_days(dt: &str) -> Self {
+        match dt.to_lowercase().as_str() {
+            "today" => Self::Days { days: 0 },
+            "yesterday" => Self::Days { days: -1 },
+            "tomorrow" => Self::Days { days: 1 },
+            _ => Self::None,
+        }
+    }
+    /// Parse weekday from English names like "monday" or "mon".
+    fn parse_weekday(dt: &str) -> Self {
+        let re = Regex::new(r"^[[:alpha:]]{3,}$").unwrap();
+        if re.is_match(dt) {
+            if let Ok(weekday) = dt.parse::<Weekday>() {
+                return Self::Weekday { weekday };
+            }
+        }
+        Self::None
+    }
+    /// Get the local date which lies `days` days from the context's date.
+    fn day(context: &Context, days: i64) -> NaiveDate {
+        let today = context.time().into_local().date();
+        today + chrono::Duration::days(days)
+    }
+    /// Get the local date of the latest `weekday` until the context's date.
+    fn latest(context: &Context, weekday: Weekday) -> NaiveDate {
+        let today = context.time().into_local().date();
+        let days =
+            (7 + today.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+        today - chrono::Duration::days(days as i64)
+    }
     /// Convert partial date and time into date and time by enriching it with data from `base`.
-    pub fn into(self, base: DateTime) -> DateTime {
-        let base: chrono::DateTime<Local> = base.into();
-        chrono::DateTime::with_timezone(
-            &match self {
-                Self::HM { hour, minute } => Local
-                    .with_ymd_and_hms(base.year(), base.month(), base.day(), hour, minute, 0)
-                    .unwrap(),
-                Self::YMDHM {
-                    year,
-                    month,
-                    day,
-                    hour,
-                    minute,
-                } => Local
-                    .with_ymd_and_hms(year, month, day, hour, minute, 0)
-                    .unwrap(),
-                Self::MDHM {
-                    month,
-                    day,
-                    hour,
-                    minute,
-                } => Local
-                    .with_ymd_and_hms(base.year(), month, day, hour, minute, 0)
-                    .unwrap(),
-                Self::YMD { year, month, day } => {
-                    Local.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
-                }
-                Self::MD { month, day } => Local
-                    .with_ymd_and_hms(base.year(), month, day, 0, 0, 0)
-                    .unwrap(),
-                Self::None => Local
-                    .with_ymd_and_hms(
-                        base.year(),
-                        base.month(),
-                        base.day(),
-                        base.hour(),
-                        base.minute(),
-                        0,
-                    )
-                    .unwrap(),
-            },
-            &Utc,
-        )
-        .into()
+    /// Relative dates and times are calculated from the time given by `context`.
+    pub fn into(self, base: DateTime, context: &Context) -> DateTime {
+        let base = base.into_local();
+        let ymd = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        let (date, hour, minute) = match self {
+            Self::HM { hour, minute } => (base.date(), hour, minute),
+            Self::YMDHM {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+            } => (ymd(year, month, day), hour, minute),
+            Self::MDHM {
+                month,
+                day,
+                hour,
+                minute,
+            } => (ymd(base.year(), month, day), hour, minute),
+            Self::YMD { year, month, day } => (ymd(year, month, day), 0, 0),
+            Self::MD { month, day } => (ymd(base.year(), month, day), 0, 0),
+            Self::Offset { minutes } => return context.time() + chrono::Duration::minutes(minutes),
+            Self::Days { days } => (Self::day(context, days), 0, 0),
+            Self::DaysHM { days, hour, minute } => (Self::day(context, days), hour, minute),
+            Self::Weekday { weekday } => (Self::latest(context, weekday), 0, 0),
+            Self::WeekdayHM {
+                weekday,
+                hour,
+                minute,
+            } => (Self::latest(context, weekday), hour, minute),
+            Self::None => (base.date(), base.hour(), base.minute()),
+        };
+        DateTime::from_local(&date.and_hms_opt(hour, minute, 0).unwrap())
     }
 }
 
@@ -245,6 +317,38 @@ impl PartialDateTime {
 #[test]
 fn test_parse_date_time() {
     PartialDateTime::parse_date_time("1.1.,12:00".into());
+    assert_eq!(
+        PartialDateTime::parse_date_time("yesterday,9:00".into()),
+        PartialDateTime::DaysHM {
+            days: -1,
+            hour: 9,
+            minute: 0
+        }
+    );
+    assert_eq!(
+        PartialDateTime::parse_date_time("yesterday 18:00".into()),
+        PartialDateTime::DaysHM {
+            days: -1,
+            hour: 18,
+            minute: 0
+        }
+    );
+    assert_eq!(
+        PartialDateTime::parse_date_time("9:00,mon".into()),
+        PartialDateTime::WeekdayHM {
+            weekday: Weekday::Mon,
+            hour: 9,
+            minute: 0
+        }
+    );
+    assert_eq!(
+        PartialDateTime::parse_date_time("-20m".into()),
+        PartialDateTime::Offset { minutes: -20 }
+    );
+    assert_eq!(
+        PartialDateTime::parse_date_time("-20m,9:00".into()),
+        PartialDateTime::None
+    );
 }
 
 /// Test time parsing.
@@ -388,3 +492,151 @@ fn test_parse_md() {
         PartialDateTime::MD { month: 2, day: 1 }
     );
 }
+
+/// Test reading dates by preferred date order.
+#[test]
+fn test_parse_date_order() {
+    assert_eq!(
+        PartialDateTime::parse_date_time_in("2/1/2023".into(), DateOrder::MDY),
+        PartialDateTime::YMD {
+            year: 2023,
+            month: 2,
+            day: 1
+        }
+    );
+    assert_eq!(
+        PartialDateTime::parse_date_time_in("2/1/2023".into(), DateOrder::DMY),
+        PartialDateTime::YMD {
+            year: 2023,
+            month: 1,
+            day: 2
+        }
+    );
+    assert_eq!(
+        PartialDateTime::parse_date_time_in("2/1,12:00".into(), DateOrder::DMY),
+        PartialDateTime::MDHM {
+            month: 1,
+            day: 2,
+            hour: 12,
+            minute: 0
+        }
+    );
+    assert_eq!(
+        PartialDateTime::parse_date_time_in("2/1".into(), DateOrder::YMD),
+        PartialDateTime::MD { month: 2, day: 1 }
+    );
+    // unambiguous formats do not depend on the preferred order
+    for order in [DateOrder::MDY, DateOrder::DMY, DateOrder::YMD] {
+        assert_eq!(
+            PartialDateTime::parse_date_time_in("2.1.".into(), order),
+            PartialDateTime::MD { month: 1, day: 2 }
+        );
+        assert_eq!(
+            PartialDateTime::parse_date_time_in("2023-2-1".into(), order),
+            PartialDateTime::YMD {
+                year: 2023,
+                month: 2,
+                day: 1
+            }
+        );
+    }
+}
+
+/// Test time offset parsing.
+#[test]
+fn test_parse_offset() {
+    assert_eq!(
+        PartialDateTime::parse_offset("-15m"),
+        PartialDateTime::Offset { minutes: -15 }
+    );
+    assert_eq!(
+        PartialDateTime::parse_offset("+1h"),
+        PartialDateTime::Offset { minutes: 60 }
+    );
+    assert_eq!(
+        PartialDateTime::parse_offset("-1h30m"),
+        PartialDateTime::Offset { minutes: -90 }
+    );
+    assert_eq!(
+        PartialDateTime::parse_offset("-1:30"),
+        PartialDateTime::Offset { minutes: -90 }
+    );
+    assert_eq!(
+        PartialDateTime::parse_offset("now-.5"),
+        PartialDateTime::Offset { minutes: -30 }
+    );
+    assert_eq!(
+        PartialDateTime::parse_offset("now+2"),
+        PartialDateTime::Offset { minutes: 120 }
+    );
+    assert_eq!(PartialDateTime::parse_offset("15m"), PartialDateTime::None);
+    assert_eq!(PartialDateTime::parse_offset("-15x"), PartialDateTime::None);
+    assert_eq!(PartialDateTime::parse_offset("-"), PartialDateTime::None);
+}
+
+/// Test relative day parsing.
+#[test]
+fn test_parse_days() {
+    assert_eq!(
+        PartialDateTime::parse_days("today"),
+        PartialDateTime::Days { days: 0 }
+    );
+    assert_eq!(
+        PartialDateTime::parse_days("Yesterday"),
+        PartialDateTime::Days { days: -1 }
+    );
+    assert_eq!(
+        PartialDateTime::parse_days("tomorrow"),
+        PartialDateTime::Days { days: 1 }
+    );
+    assert_eq!(PartialDateTime::parse_days("2day"), PartialDateTime::None);
+}
+
+/// Test weekday parsing.
+#[test]
+fn test_parse_weekday() {
+    assert_eq!(
+        PartialDateTime::parse_weekday("monday"),
+        PartialDateTime::Weekday {
+            weekday: Weekday::Mon
+        }
+    );
+    assert_eq!(
+        PartialDateTime::parse_weekday("Fri"),
+        PartialDateTime::Weekday {
+            weekday: Weekday::Fri
+        }
+    );
+    assert_eq!(PartialDateTime::parse_weekday("mo"), PartialDateTime::None);
+    assert_eq!(
+        PartialDateTime::parse_weekday("moonday"),
+        PartialDateTime::None
+    );
+}
+
+/// Test conversion of relative dates and times.
+#[test]
+fn test_into_relative() {
+    // Wednesday
+    let context = Context::new_test("2023-02-01 12:00");
+    let base: DateTime = "2023-01-15 08:00".into();
+
+    let into = |dt: &str| {
+        PartialDateTime::parse(Some(dt.into()))
+            .unwrap()
+            .into(base, &context)
+    };
+
+    assert_eq!(into("-20m"), "2023-02-01 11:40".into());
+    assert_eq!(into("+1h"), "2023-02-01 13:00".into());
+    assert_eq!(into("-13h"), "2023-01-31 23:00".into());
+    assert_eq!(into("today"), "2023-02-01 00:00".into());
+    assert_eq!(into("yesterday"), "2023-01-31 00:00".into());
+    assert_eq!(into("yesterday,18:00"), "2023-01-31 18:00".into());
+    assert_eq!(into("tomorrow,8:00"), "2023-02-02 08:00".into());
+    assert_eq!(into("wed,9:00"), "2023-02-01 09:00".into());
+    assert_eq!(into("monday"), "2023-01-30 00:00".into());
+    assert_eq!(into("thursday,17:30"), "2023-01-26 17:30".into());
+    // absolute formats still get enriched by `base`
+    assert_eq!(into("9:00"), "2023-01-15 09:00".into());
+}
@@ -28,6 +28,12 @@ impl PositionalRanges {
     }
 }
 
+impl Default for PositionalRanges {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl std::fmt::Display for PositionalRanges {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -97,7 +97,7 @@ impl Range {
         let pt = PartialDateTime::parse_opt(Some(list.to_string()));
         match pt {
             PartialDateTime::None => Self::None,
-            _ => Range::Day(Date::from(pt.into(context.time()))),
+            _ => Range::Day(pt.into(context.time(), context).local_date()),
         }
     }
     /// Parse `TimeRange`.
@@ -119,18 +119,18 @@ impl Range {
             match (from, to) {
                 (PartialDateTime::None, PartialDateTime::None) => Self::None,
                 (from, PartialDateTime::None) => {
-                    Self::TimeRange(from.into(context.time()), context.time())
+                    Self::TimeRange(from.into(context.time(), context), context.time())
                 }
                 (PartialDateTime::None, to) => {
                     use chrono::{TimeZone, Utc};
                     Self::TimeRange(
                         Utc.with_ymd_and_hms(1900, 1, 1, 0, 0, 0).unwrap().into(),
-                        to.into(context.time()) + Duration::days(1),
+                        to.into(context.time(), context) + Duration::days(1),
                     )
                 }
                 (from, to) => {
-                    let from = from.into(context.time());
-                    Self::TimeRange(from, to.into(from) + Duration::days(1))
+                    let from = from.into(context.time(), context);
+                    Self::TimeRange(from, to.into(from, context) + Duration::days(1))
                 }
             }
         } else {
@@ -144,7 +144,7 @@ impl Range {
             let pt = PartialDateTime::parse_opt(Some(cap[1].to_string()));
             return match pt {
                 PartialDateTime::None => Self::None,
-                _ => Range::Since(pt.into(context.time())),
+                _ => Range::Since(pt.into(context.time(), context)),
             };
         }
         Self::None
@@ -214,7 +214,12 @@ fn test_parse_time_range() {
     //
     let january = Range::parse(Some("1.1...31.1.".into()), &context).unwrap();
 
-    assert!(jobs._filter(&january, &TagSet::new()).unwrap().len() == 1);
+    assert!(
+        jobs._filter(&january, &TagSet::new(), &context)
+            .unwrap()
+            .len()
+            == 1
+    );
 
     assert_eq!(
         january,
@@ -0,0 +1,90 @@
+//! Detect an open job which most likely has been forgotten to end.
+
+use super::prelude::*;
+use chrono::Days;
+
+/// Reason why the open job seems to be forgotten.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Reminder {
+    /// Open job at position `usize` lasts longer than the maximum work hours and might have ended at the given time.
+    Overtime(usize, u32, DateTime),
+    /// Open job at position `usize` has been started before today and might have ended at the given time.
+    Overnight(usize, DateTime),
+}
+
+impl Reminder {
+    /// Position of the open job.
+    pub fn pos(&self) -> usize {
+        match self {
+            Reminder::Overtime(pos, _, _) | Reminder::Overnight(pos, _) => *pos,
+        }
+    }
+    /// Last plausible time at which the open job might have ended.
+    pub fn end(&self) -> DateTime {
+        match self {
+            Reminder::Overtime(_, _, end) | Reminder::Overnight(_, end) => *end,
+        }
+    }
+    /// Create a command which ends the open job at the last plausible time.
+    pub fn command(&self) -> Command {
+        Command::Edit {
+            pos: Some(self.pos()),
+            start: None,
+            end: EndOrDuration::End(self.end()),
+            message: None,
+            tags: None,
+            billable: None,
+        }
+    }
+}
+
+impl std::fmt::Display for Reminder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reminder::Overtime(pos, max_hours, _) => write!(
+                f,
+                "Open job at position {} lasts longer than {max_hours} hours.",
+                pos + 1
+            ),
+            Reminder::Overnight(pos, _) => write!(
+                f,
+                "Open job at position {} has been started before today.",
+                pos + 1
+            ),
+        }
+    }
+}
+
+/// Check if the open job (if any) exceeds it's maximum work hours or crosses midnight.
+pub fn remind(jobs: &Jobs, context: &Context) -> Option<Reminder> {
+    let (pos, job) = jobs.get_open_with_pos()?;
+    let properties = jobs.configuration.get_and_why(&job.tags).1;
+
+    // end of the working hours (or the day boundary) at the day the job started
+    let date = (job.start.into_local() - locale::day_boundary()).date();
+    let work_end = jobs
+        .configuration
+        .settings
+        .work_hours
+        .map(|work_hours| DateTime::from_local(&date.and_time(work_hours.end)))
+        .filter(|end| *end > job.start)
+        .unwrap_or(DateTime::from_local(
+            &(date
+                .checked_add_days(Days::new(1))
+                .unwrap_or(date)
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                + locale::day_boundary()),
+        ));
+
+    if let Some(max_hours) = properties.max_hours {
+        if job.duration(context).num_minutes() > max_hours as i64 * 60 {
+            let end = job.start + Duration::minutes(max_hours as i64 * 60);
+            return Some(Reminder::Overtime(pos, max_hours, end.min(work_end)));
+        }
+    }
+    if job.start.date() < context.date() {
+        return Some(Reminder::Overnight(pos, work_end));
+    }
+    None
+}
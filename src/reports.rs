@@ -10,43 +10,38 @@ use termion::{color::*, style};
 
 /// Report in calendar form.
 pub fn report<W: std::io::Write>(mut w: W, jobs: &JobList, context: &Context) -> Result<(), Error> {
+    // report with the settings of the database these jobs stem from
+    locale::init(&jobs.configuration.settings);
     // resort job hours into nested maps of year -> month -> day -> hours
-    type Days = HashMap<u32, HashMap<Option<String>, f64>>;
+    type Days = HashMap<u32, HashMap<(Option<String>, bool), f64>>;
     type Month = HashMap<u32, Days>;
     let mut years: HashMap<i32, Month> = HashMap::new();
     for (_, job) in jobs.iter() {
-        for job in job.split(context) {
+        // get configuration for the job's tags and the tag which was relevant
+        let (tag, properties) = jobs.configuration.get_and_why(&job.tags);
+        let billable = job.is_billable(properties);
+        // get hours per day (unrounded if the sum of the day will be rounded)
+        for (start, job_hours) in job.hours_per_day(properties, context) {
             // insert year if not already in map
-            let year = job.start.year();
-            years.entry(year).or_insert_with(HashMap::new);
+            let year = start.year();
+            years.entry(year).or_default();
             // get months in that year
             let months = years.get_mut(&year).unwrap();
 
             // insert month if not already in year
-            let month = job.start.month();
-            months.entry(month).or_insert_with(HashMap::new);
+            let month = start.month();
+            months.entry(month).or_default();
             // get days in that month
             let days = months.get_mut(&month).unwrap();
 
             // insert day if not already in month
-            let day = job.start.day();
-            days.entry(day).or_insert_with(HashMap::new);
+            let day = start.day();
+            days.entry(day).or_default();
             // get tagged hours of that day
             let tag_hours = days.get_mut(&day).unwrap();
 
-            // get configuration for the job's tags and the tag which was relevant
-            let (tag, properties) = jobs.configuration.get_and_why(&job.tags);
-
-            // get hours for that tag
-            let job_hours = job.hours(properties);
-            if !tag_hours.contains_key(&tag) {
-                tag_hours.insert(tag.clone(), 0.0);
-            }
-            // get hours of that day and that tag
-            let hours = tag_hours.get_mut(&tag).unwrap();
-
-            // add job hours to that day and that tag
-            *hours += job_hours;
+            // add job hours to that day and that tag (separated by billability)
+            *tag_hours.entry((tag.clone(), billable)).or_default() += job_hours;
         }
     }
 
@@ -111,22 +106,38 @@ pub fn report<W: std::io::Write>(mut w: W, jobs: &JobList, context: &Context) ->
                     let mut day_hours = 0.0;
                     let mut day_costs: Option<f64> = None;
                     let mut exceeded = false;
-                    for (tag, hours) in tag_hours {
+                    let mut hours_by_tag: HashMap<&Option<String>, f64> = HashMap::new();
+                    for ((tag, billable), hours) in tag_hours {
                         let properties = jobs
                             .configuration
                             .get_checked(&tag.into())
                             .expect("unexpected tag collision");
-                        if let Some(max_hours) = properties.max_hours {
-                            if *hours > max_hours as f64 {
-                                exceeded = true;
-                            }
-                        }
+                        // maybe round the sum of this day
+                        let hours = if properties.rounding_level() == RoundingLevel::Day {
+                            properties.round(*hours)
+                        } else {
+                            *hours
+                        };
+                        *hours_by_tag.entry(tag).or_default() += hours;
                         day_hours += hours;
                         if let Some(rate) = properties.rate {
                             if day_costs.is_none() {
                                 day_costs = Some(0.0);
                             }
-                            day_costs = Some(day_costs.unwrap() + hours * rate);
+                            if *billable {
+                                day_costs = Some(day_costs.unwrap() + hours * rate);
+                            }
+                        }
+                    }
+                    for (tag, hours) in hours_by_tag {
+                        let properties = jobs
+                            .configuration
+                            .get_checked(&tag.into())
+                            .expect("unexpected tag collision");
+                        if let Some(max_hours) = properties.max_hours {
+                            if hours > max_hours as f64 {
+                                exceeded = true;
+                            }
                         }
                     }
                     // print hours at this day and mark yellow if exceeded and red if >24h/day
@@ -187,20 +198,7 @@ pub fn report<W: std::io::Write>(mut w: W, jobs: &JobList, context: &Context) ->
         }
     }
 
-    let pay = {
-        if let Some(pay) = jobs.pay_overall() {
-            format!(" = ${}", format::pay_pure(pay),)
-        } else {
-            String::new()
-        }
-    };
-    writeln!(
-        w,
-        "Total: {} job(s), {} hours{}",
-        jobs.len(),
-        format::hours_pure(jobs.hours_overall()),
-        pay,
-    )?;
+    writeln!(w, "Total: {}", jobs.summary(context))?;
 
     Ok(())
 }
@@ -0,0 +1,60 @@
+//! Minimal status of the open job (e.g. to be shown within a shell prompt).
+
+use super::prelude::*;
+
+/// Format of the status if not given.
+pub const STATUS_FORMAT: &str = "{tags} {message} {duration}";
+
+/// Format the open job by replacing the placeholders `{pos}`, `{start}`, `{tags}`, `{message}`,
+/// `{duration}` and `{hours}` within the given format or as JSON if format is `json`.
+///
+/// Returns an empty string (or `null` in JSON) if there is no open job.
+pub fn status(jobs: &Jobs, format: &str, context: &Context) -> Result<String, Error> {
+    format_status(jobs.get_open_with_pos(), format, context)
+}
+
+/// Format the given open job (and it's position) like [status] does.
+pub fn format_status(
+    open: Option<(usize, &Job)>,
+    format: &str,
+    context: &Context,
+) -> Result<String, Error> {
+    if format == "json" {
+        return serde_json::to_string(&open.map(|(pos, job)| {
+            serde_json::json!({
+                "pos": pos + 1,
+                "start": job.start.to_rfc3339(),
+                "tags": job.tags.0,
+                "message": first_line(job),
+                "minutes": job.duration(context).num_minutes(),
+                "paused": job.is_paused(),
+            })
+        }))
+        .map_err(Error::Json);
+    }
+    Ok(if let Some((pos, job)) = open {
+        let minutes = job.duration(context).num_minutes();
+        format
+            .replace("{pos}", &(pos + 1).to_string())
+            .replace("{start}", &job.start.format(locale::clock().time_format()))
+            .replace("{tags}", &job.tags.0.join(","))
+            .replace("{message}", first_line(job))
+            .replace(
+                "{duration}",
+                &format!("{}:{:02}", minutes / 60, minutes % 60),
+            )
+            .replace("{hours}", &format!("{:.2}", minutes as f64 / 60.0))
+            .trim()
+            .to_string()
+    } else {
+        String::new()
+    })
+}
+
+/// Get first line of the job's message.
+fn first_line(job: &Job) -> &str {
+    job.message
+        .as_deref()
+        .and_then(|message| message.lines().next())
+        .unwrap_or_default()
+}
@@ -86,6 +86,12 @@ impl TagSet {
             .collect();
     }
 }
+impl Default for TagSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl std::fmt::Display for TagSet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (n, tag) in self.0.iter().enumerate() {
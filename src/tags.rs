@@ -1,18 +1,19 @@
 //! Static global tag register :/ to manage colorization of tags.
 
 use super::prelude::*;
+use std::sync::RwLock;
 
 /// Tag register.
-static mut TAGS: TagSet = TagSet::new();
+static TAGS: RwLock<TagSet> = RwLock::new(TagSet::new());
 
 /// initialize tag index `TAGS` from a list of jobs
 pub fn init(jobs: &Jobs) {
-    unsafe { TAGS = jobs.tags() }
+    *TAGS.write().unwrap() = jobs.tags()
 }
 
 /// Update register with job's tags.
 pub fn update(job: &Job) {
-    unsafe { TAGS.insert_many(job.tags.clone()) }
+    TAGS.write().unwrap().insert_many(job.tags.clone())
 }
 
 /// Decorate tag with color.
@@ -45,7 +46,5 @@ pub fn format(f: &mut std::fmt::Formatter, tag: &String) -> std::fmt::Result {
 
 /// get the position of a tag within the tag index `TAGS` (to assign a color)
 fn position(tag: &String) -> Option<usize> {
-    unsafe {
-        TAGS.0.iter().position(|t| t == tag)
-    }
+    TAGS.read().unwrap().0.iter().position(|t| t == tag)
 }